@@ -6,7 +6,7 @@ use tuit::std::stdout_render::StdoutRenderer;
 use tuit::style::{Ansi4, Colour};
 use tuit::terminal::ConstantSize;
 use tuit::widgets::BoundingBox;
-use tuit::widgets::builtins::{Buttons, Sweeper, Text};
+use tuit::widgets::builtins::{Sweeper, Text};
 
 fn main() {
     env_logger::builder().filter_level(LevelFilter::Trace).init();
@@ -15,11 +15,6 @@ fn main() {
 
     let yellow = Sweeper::of_colour(Colour::Ansi16(Ansi4::Yellow));
 
-    let mut buttons = Buttons::new(&[" No ", " Yes "]).select_last();
-
-    buttons.selected_button_style = buttons.selected_button_style.bg(Colour::Ansi16(Ansi4::Red)).underlined().font_weight(600);
-    buttons.unselected_button_style = buttons.unselected_button_style.bg(Colour::Ansi16(Ansi4::BrightRed));
-
     let mut continue_str = String::from("T");
 
     loop {
@@ -1,12 +1,69 @@
-//! Demonstrates centered prompts.
+//! Demonstrates centered prompts that are driven by keyboard and mouse input.
+//!
+//! Use the arrow keys or Tab to move between the buttons, and Enter, Space or a click to choose one.
+//! Press Escape or `q` to quit without choosing.
+
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
 
 use tuit::prelude::*;
 use tuit::std::stdout_render::StdoutRenderer;
 use tuit::style::{Ansi4, Colour::Ansi16, Style};
-use tuit::terminal::ConstantSize;
+use tuit::terminal::{AnsiDecoder, ConstantSize, Key, KeyState, UpdateInfo};
 use tuit::widgets::builtins::sweeper::Sweeper;
 use tuit::widgets::builtins::{Buttons, Text};
 
+/// Enables mouse reports, using the SGR encoding that [`AnsiDecoder`] understands.
+const ENABLE_MOUSE: &str = "\x1b[?1000h\x1b[?1006h";
+/// Disables mouse reports again.
+const DISABLE_MOUSE: &str = "\x1b[?1006l\x1b[?1000l";
+
+/// Stops the terminal from buffering lines and echoing keys, and restores its settings when dropped.
+///
+/// Reads give up after a tenth of a second without input, which is how a lone Escape key press gets
+/// told apart from the start of an escape sequence.
+///
+/// This uses `stty`, so it only works on Unix-like systems. Without it, input is only read after
+/// Enter is pressed and Escape can't be used to quit, but the prompt still works.
+struct RawInput {
+    saved: Option<String>,
+}
+
+impl RawInput {
+    fn enable() -> Self {
+        let saved = Command::new("stty")
+            .arg("-g")
+            .stdin(Stdio::inherit())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned());
+
+        if saved.is_some() {
+            Command::new("stty").args(["-icanon", "-echo", "min", "0", "time", "1"]).stdin(Stdio::inherit()).status().ok();
+        }
+
+        print!("{ENABLE_MOUSE}");
+
+        Self { saved }
+    }
+
+    /// Returns whether reads time out, instead of only returning nothing once the input is closed.
+    const fn times_out(&self) -> bool {
+        self.saved.is_some()
+    }
+}
+
+impl Drop for RawInput {
+    fn drop(&mut self) {
+        print!("{DISABLE_MOUSE}");
+
+        if let Some(saved) = &self.saved {
+            Command::new("stty").arg(saved).stdin(Stdio::inherit()).status().ok();
+        }
+    }
+}
+
 #[cfg(not(feature = "ansi_renderer"))]
 fn main() {
     println!("You must apply the stdout_render feature to view this example. Use `cargo --features stdout_render`");
@@ -15,16 +72,9 @@ fn main() {
 #[cfg(feature = "ansi_renderer")]
 fn main() {
     let mut terminal: ConstantSize<57, 14> = ConstantSize::new();
-    //
-    // let text = CenteredText::new("Hello world!");
-    //
-    // text.drawn(&mut terminal)
-    //     .expect("This method CAN fail, but only if the prompt is too large. Here, it is not.");
-
     let mut renderer = StdoutRenderer::default();
 
     let sweeper = Sweeper::of_colour(Ansi16(Ansi4::BrightCyan));
-    sweeper.drawn(&mut terminal).ok();
 
     let query = Text::new("Continue?").with_margin(1);
     let mut buttons = Buttons::new(&[" Yes ", " No "]);
@@ -33,11 +83,64 @@ fn main() {
 
     let buttons = buttons.select_last();
 
-    let prompt = query.on_top_of(buttons).centered();
+    let mut prompt = query.on_top_of(buttons).centered();
+
+    let raw_input = RawInput::enable();
+    let mut decoder = AnsiDecoder::new();
+
+    let choice = loop {
+        sweeper.drawn(&mut terminal).ok();
+        prompt.use_backdrop(Ansi16(Ansi4::Yellow)).drawn(&mut terminal).expect("Infallible");
+        prompt.drawn(&mut terminal).ok();
+
+        // Move back to the top-left and clear the screen before drawing the next frame.
+        print!("\x1b[H\x1b[2J");
+        renderer.render(&terminal).ok();
+        std::io::stdout().flush().ok();
+
+        let update_info = loop {
+            let mut byte = [0];
+
+            match std::io::stdin().read(&mut byte) {
+                Ok(1..) => {
+                    if let Some(update_info) = decoder.feed(byte[0]) {
+                        break Some(update_info);
+                    }
+                }
+                // Nothing arrived in time, so an escape byte on its own was the Escape key.
+                Ok(0) if raw_input.times_out() => {
+                    if let Some(update_info) = decoder.flush() {
+                        break Some(update_info);
+                    }
+                }
+                // The input was closed.
+                Ok(0) | Err(_) => break None,
+            }
+        };
+
+        let Some(update_info) = update_info else {
+            break None;
+        };
+
+        if matches!(
+            update_info,
+            UpdateInfo::KeyboardInput(Key::Escape, KeyState::KeyDown) | UpdateInfo::KeyboardCharacter('q', KeyState::KeyDown)
+        ) {
+            break None;
+        }
+
+        prompt.update(update_info, &terminal).ok();
 
-    prompt.use_backdrop(Ansi16(Ansi4::Yellow)).drawn(&mut terminal).expect("Infallible");
+        if let Some(button) = prompt.inner().lower_widget.activated() {
+            break Some(button);
+        }
+    };
 
-    prompt.drawn(&mut terminal).ok();
+    drop(raw_input);
 
-    renderer.render(terminal).ok();
+    match choice {
+        Some(0) => println!("\nContinuing!"),
+        Some(_) => println!("\nNot continuing."),
+        None => println!("\nNo choice was made."),
+    }
 }
@@ -1,2 +1,8 @@
 //! Widgets that rely on the availability of allocation features.
-//! Currently, there are none.
+//!
+//! With allocation available, [`String`](alloc::string::String) implements
+//! [`TextBuffer`](crate::widgets::builtins::TextBuffer), so a
+//! [`TextArea`](crate::widgets::builtins::TextArea) can grow to fit any amount of text.
+
+#[cfg(feature = "builtin_widgets")]
+mod text_buffer;
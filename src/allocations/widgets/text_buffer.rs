@@ -0,0 +1,41 @@
+use core::ops::Range;
+
+use crate::allocations::alloc::string::String;
+use crate::widgets::builtins::TextBuffer;
+
+/// A [`TextBuffer`] that grows as needed, so inserting text never fails.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, KeyState, UpdateInfo};
+/// use tuit::widgets::builtins::TextArea;
+///
+/// let terminal: ConstantSize<20, 5> = ConstantSize::new();
+/// let mut editor = TextArea::new(String::from("Hello"));
+///
+/// for character in ", world!".chars() {
+///     editor.update(UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown), &terminal).unwrap();
+/// }
+///
+/// // The cursor starts at the beginning of the text.
+/// assert_eq!(editor.into_inner(), ", world!Hello");
+/// ```
+impl TextBuffer for String {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn insert_str(&mut self, index: usize, text: &str) -> Option<()> {
+        Self::insert_str(self, index, text);
+
+        Some(())
+    }
+
+    fn remove(&mut self, range: Range<usize>) {
+        self.replace_range(range, "");
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+}
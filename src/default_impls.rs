@@ -1,9 +1,9 @@
 use core::cmp::Ordering;
-use core::ops::{BitOr, Deref, DerefMut};
+use core::ops::{BitOr, BitOrAssign, Deref, DerefMut};
 use crate::draw::Renderer;
 use crate::prelude::{Metadata, Terminal, TerminalConst, TerminalMut};
 use crate::style::{Ansi4, Style};
-use crate::terminal::{Cell, UpdateInfo, UpdateResult};
+use crate::terminal::{Cell, Key, Modifiers, UpdateInfo, UpdateResult};
 use crate::terminal::Rectangle;
 use crate::widgets::Widget;
 
@@ -14,6 +14,28 @@ impl BitOr for Ansi4 {
         (self as u8) << 4 | rhs as u8
     }
 }
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+impl From<u8> for Key {
+    fn from(code: u8) -> Self {
+        Self::from_code(code)
+    }
+}
+impl From<Key> for u8 {
+    fn from(key: Key) -> Self {
+        key.code()
+    }
+}
 impl From<Rectangle> for ((usize, usize), (usize, usize)) {
     fn from(value: Rectangle) -> Self {
         (value.left_top(), value.right_bottom())
@@ -27,13 +49,12 @@ impl From<((usize, usize), (usize, usize))> for Rectangle {
 
 impl PartialOrd for Rectangle {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.area().cmp(&other.area()))
+        Some(self.cmp(other))
     }
 }
 impl Ord for Rectangle {
     fn cmp(&self, other: &Self) -> Ordering {
-        #[allow(clippy::unwrap_used)]
-        self.partial_cmp(other).unwrap()
+        self.area().cmp(&other.area())
     }
 }
 
@@ -78,6 +99,18 @@ impl<T: DerefMut<Target: Widget>> Widget for T {
     fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
         self.deref().draw(terminal)
     }
+
+    fn focusable(&self) -> usize {
+        self.deref().focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.deref_mut().set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.deref().focused()
+    }
 }
 
 impl<T: TerminalConst + TerminalMut + Metadata> Terminal for T {}
\ No newline at end of file
@@ -0,0 +1,231 @@
+use core::fmt::{Display, Formatter};
+
+use crate::style::{Colour, Style};
+
+/// The attributes that a terminal actually displays, after all the `None`s in a [`Style`] have been
+/// filled in with the terminal's defaults.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Attributes {
+    fg_colour: Colour,
+    bg_colour: Colour,
+    bold: bool,
+    underline: bool,
+    invert: bool,
+}
+
+impl Attributes {
+    /// The attributes that the terminal uses after an SGR reset (`ESC[0m`).
+    const DEFAULT: Self = Self {
+        fg_colour: Colour::TerminalDefault,
+        bg_colour: Colour::TerminalDefault,
+        bold: false,
+        underline: false,
+        invert: false,
+    };
+
+    const fn of(style: Style) -> Self {
+        let fg_colour = match style.fg_colour {
+            Some(colour) => colour,
+            None => Colour::TerminalDefault,
+        };
+
+        let bg_colour = match style.bg_colour {
+            Some(colour) => colour,
+            None => Colour::TerminalDefault,
+        };
+
+        let bold = match style.font_weight {
+            Some(weight) => weight >= 700,
+            None => false,
+        };
+
+        let underline = matches!(style.underline, Some(true));
+        let invert = matches!(style.invert, Some(true));
+
+        Self {
+            fg_colour,
+            bg_colour,
+            bold,
+            underline,
+            invert,
+        }
+    }
+}
+
+/// Keeps track of the SGR ("Select Graphic Rendition") attributes that are currently active on
+/// an ANSI terminal.
+///
+/// Instead of styling every single character, [`SgrState::transition`] only emits the attributes
+/// that differ from the ones that are already active. This makes the output of large, uniformly
+/// styled areas (like a [`Sweeper`](crate::widgets::builtins::Sweeper) backdrop) a lot smaller.
+///
+/// [`SgrState`] expects the [`Style`]s it receives to already be *resolved*. Since a `None` field
+/// in a [`Style`] means "use the value from the preceding cell", renderers should keep a running
+/// style and fill it in using [`Style::inherits`] as they walk through the terminal's cells. Any
+/// field that is still `None` after that uses the terminal's default.
+///
+/// ```
+/// use tuit::draw::ansi::SgrState;
+/// use tuit::style::{Ansi4, Style};
+///
+/// let mut state = SgrState::new();
+/// let red = Style::new().fg_ansi4(Ansi4::Red);
+///
+/// assert_eq!(state.transition(red).to_string(), "\x1b[31m");
+/// // The colour is already active, so there is nothing to emit.
+/// assert_eq!(state.transition(red).to_string(), "");
+/// // Only the underline gets added.
+/// assert_eq!(state.transition(red.underlined()).to_string(), "\x1b[4m");
+/// // Going back to the terminal defaults.
+/// assert_eq!(state.transition(Style::new()).to_string(), "\x1b[39;24m");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SgrState {
+    active: Attributes,
+}
+
+impl Default for SgrState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SgrState {
+    /// Create a new [`SgrState`]. It assumes that the terminal is currently using its default
+    /// attributes, so make sure to write [`SgrState::RESET`] first if you are unsure of that.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            active: Attributes::DEFAULT,
+        }
+    }
+
+    /// The escape sequence that resets all SGR attributes to the terminal's defaults.
+    pub const RESET: &'static str = "\x1b[0m";
+
+    /// Returns whether the active attributes are the terminal's defaults.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.active == Attributes::DEFAULT
+    }
+
+    /// Switch to the given resolved [`Style`], returning the escape sequence that performs the
+    /// switch. The returned value is empty if there is nothing to change.
+    #[must_use = "the returned escape sequence must be written to the terminal"]
+    pub const fn transition(&mut self, style: Style) -> SgrTransition {
+        let from = self.active;
+        let to = Attributes::of(style);
+
+        self.active = to;
+
+        SgrTransition { from, to }
+    }
+
+    /// Switch back to the terminal's default attributes, returning the escape sequence that performs
+    /// the switch. The returned value is empty if the defaults are already active.
+    #[must_use = "the returned escape sequence must be written to the terminal"]
+    pub fn reset(&mut self) -> &'static str {
+        if self.is_default() {
+            return "";
+        }
+
+        self.active = Attributes::DEFAULT;
+
+        Self::RESET
+    }
+}
+
+/// The escape sequence returned by [`SgrState::transition`]. Use its [`Display`] implementation
+/// to write it to the terminal.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SgrTransition {
+    from: Attributes,
+    to: Attributes,
+}
+
+impl SgrTransition {
+    /// Returns whether the transition does not need to emit anything.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+impl Display for SgrTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self { from, to } = self;
+
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        f.write_str("\x1b[")?;
+
+        let mut separator = "";
+        let mut parameter = |f: &mut Formatter<'_>, parameter: &dyn Display| {
+            let result = write!(f, "{separator}{parameter}");
+            separator = ";";
+            result
+        };
+
+        if from.fg_colour != to.fg_colour {
+            parameter(f, &ColourParameter(to.fg_colour, Layer::Foreground))?;
+        }
+
+        if from.bg_colour != to.bg_colour {
+            parameter(f, &ColourParameter(to.bg_colour, Layer::Background))?;
+        }
+
+        if from.bold != to.bold {
+            parameter(f, if to.bold { &1 } else { &22 })?;
+        }
+
+        if from.underline != to.underline {
+            parameter(f, if to.underline { &4 } else { &24 })?;
+        }
+
+        if from.invert != to.invert {
+            parameter(f, if to.invert { &7 } else { &27 })?;
+        }
+
+        f.write_str("m")
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Layer {
+    Foreground,
+    Background,
+}
+
+/// Formats a [`Colour`] as SGR parameters.
+struct ColourParameter(Colour, Layer);
+
+impl Display for ColourParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self(colour, layer) = self;
+
+        // The extended colour parameters are the same for both layers, only the prefix changes.
+        let extended = match layer {
+            Layer::Foreground => 38,
+            Layer::Background => 48,
+        };
+
+        match colour {
+            Colour::Ansi16(ansi) => {
+                let ansi = *ansi as u8;
+                let (base, offset) = if ansi < 8 { (30, ansi) } else { (90, ansi - 8) };
+                let base = match layer {
+                    Layer::Foreground => base,
+                    Layer::Background => base + 10,
+                };
+
+                write!(f, "{}", base + offset)
+            }
+            Colour::Ansi256(index) => write!(f, "{extended};5;{index}"),
+            Colour::Rgb24(r, g, b) => write!(f, "{extended};2;{r};{g};{b}"),
+            Colour::Luma8(luma) => write!(f, "{extended};2;{luma};{luma};{luma}"),
+            Colour::TerminalDefault => write!(f, "{}", extended + 1),
+        }
+    }
+}
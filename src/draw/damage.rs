@@ -0,0 +1,281 @@
+use core::fmt::Write;
+
+use anyhow::anyhow;
+
+use crate::draw::ansi::SgrState;
+use crate::draw::{Glyphs, Renderer};
+use crate::prelude::{Terminal, TerminalConst};
+use crate::style::Style;
+#[allow(unused_imports)] // used in docs.
+use crate::terminal::Cell;
+use crate::terminal::width;
+
+/// A [`Renderer`] that remembers the last frame it rendered, and only writes the [`Cell`]s that
+/// have changed since then.
+///
+/// Each run of changed cells is preceded by an ANSI cursor-positioning escape sequence, so
+/// unchanged cells are never re-sent. Styles are written using an [`SgrState`], so only the
+/// attributes that change between the written cells are emitted. This is useful when rendering over slow links, like serial
+/// consoles.
+///
+/// The previous frame is stored inside another [`Terminal`] of the same size, which means that the
+/// [`DiffRenderer`] does not need to allocate. Any time the dimensions of the rendered terminal
+/// don't match the dimensions of the stored frame, the whole terminal is redrawn.
+///
+/// ```
+/// use tuit::draw::DiffRenderer;
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+/// let mut renderer = DiffRenderer::new(String::new(), ConstantSize::<20, 5>::new());
+///
+/// // The first frame is always drawn in full.
+/// renderer.render(&terminal).expect("Writing to a `String` never fails");
+/// renderer.writer.clear();
+///
+/// // Nothing has changed, so nothing gets written.
+/// renderer.render(&terminal).expect("Writing to a `String` never fails");
+/// assert!(renderer.writer.is_empty());
+///
+/// terminal.cell_mut(4, 2).expect("Within bounds").character = 'x';
+///
+/// // Only the changed cell gets written, after moving the cursor to row 3, column 5.
+/// renderer.render(&terminal).expect("Writing to a `String` never fails");
+/// assert!(renderer.writer.starts_with("\x1b[3;5H"));
+/// assert!(renderer.writer.contains('x'));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DiffRenderer<W, P> {
+    /// The writer that the escape codes get written to.
+    pub writer: W,
+    /// The last frame that was rendered.
+    previous: P,
+    /// Whether the next frame must be drawn in full.
+    invalidated: bool,
+}
+
+impl<W: Write, P: Terminal> DiffRenderer<W, P> {
+    /// Create a new [`DiffRenderer`] that writes to `writer`, and stores the last rendered frame
+    /// inside `previous`.
+    ///
+    /// The first call to [`Renderer::render`] always draws the entire terminal.
+    #[must_use]
+    pub const fn new(writer: W, previous: P) -> Self {
+        Self {
+            writer,
+            previous,
+            invalidated: true,
+        }
+    }
+
+    /// Force the next call to [`Renderer::render`] to draw the entire terminal.
+    ///
+    /// Use this when the screen's contents may have been changed by something else, like after
+    /// the terminal was cleared or resized.
+    pub const fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
+    /// Get a reference to the last frame that was rendered.
+    #[must_use]
+    pub const fn previous(&self) -> &P {
+        &self.previous
+    }
+
+    /// Consume the [`DiffRenderer`] and return its writer and the last rendered frame.
+    pub fn into_inner(self) -> (W, P) {
+        (self.writer, self.previous)
+    }
+
+    fn render_full(&mut self, terminal: &impl TerminalConst) -> crate::Result<()> {
+        write_full(&mut self.writer, terminal)?;
+
+        // The stored frame can only be kept up-to-date if it's the same size as the terminal.
+        self.invalidated = self.previous.dimensions() != terminal.dimensions();
+
+        if !self.invalidated {
+            for (stored, cell) in self.previous.cells_mut().zip(terminal.cells()) {
+                *stored = *cell;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write, P: Terminal> Renderer for DiffRenderer<W, P> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        if self.invalidated || self.previous.dimensions() != terminal.dimensions() {
+            return self.render_full(&terminal);
+        }
+
+        let mut pen = Pen::new();
+        let width = terminal.width();
+
+        // Styles inherit from the preceding cell, so a cell whose own style hasn't changed may
+        // still look different if a cell before it has changed. Compare the resolved styles.
+        let (mut stored_style, mut style) = (Style::new(), Style::new());
+        // Likewise, whether a cell is displayed at all depends on whether the cell before it is wide.
+        let (mut stored_glyphs, mut glyphs) = (Glyphs::new(width), Glyphs::new(width));
+
+        for (idx, (stored, cell)) in self.previous.cells_mut().zip(terminal.cells()).enumerate() {
+            stored_style = stored.style.inherits(stored_style);
+            style = cell.style.inherits(style);
+
+            let stored_glyph = stored_glyphs.next(stored);
+            let glyph = glyphs.next(cell);
+
+            *stored = *cell;
+
+            if !changed((stored_glyph, stored_style), (glyph, style)) {
+                continue;
+            }
+
+            if let Some(character) = glyph {
+                pen.write_at(&mut self.writer, (idx % width, idx / width), character, style)?;
+            }
+        }
+
+        pen.finish(&mut self.writer)
+    }
+}
+
+/// Write only the [`Cell`]s of `current` that differ from the [`Cell`]s of `previous`.
+///
+/// This is the stateless version of [`DiffRenderer`], for when you already keep a snapshot of the
+/// last frame around yourself. If the two terminals aren't the same size, every cell of `current`
+/// is written.
+///
+/// ```
+/// use tuit::draw::render_diff;
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+///
+/// let previous: ConstantSize<10, 10> = ConstantSize::new();
+/// let mut current = previous;
+///
+/// current.cell_mut(0, 0).expect("Within bounds").character = 'a';
+/// current.cell_mut(1, 0).expect("Within bounds").character = 'b';
+///
+/// let mut output = String::new();
+/// render_diff(&mut output, &previous, &current).expect("Writing to a `String` never fails");
+///
+/// // Both cells are on the same row, so the cursor only needs to be moved once.
+/// assert_eq!(output.matches("\x1b[1;1H").count(), 1);
+/// assert!(!output.contains("\x1b[1;2H"));
+/// ```
+///
+/// # Errors
+///
+/// This will return an [`Err`] if writing to `writer` fails.
+pub fn render_diff(
+    writer: &mut impl Write,
+    previous: &impl TerminalConst,
+    current: &impl TerminalConst,
+) -> crate::Result<()> {
+    if previous.dimensions() != current.dimensions() {
+        return write_full(writer, current);
+    }
+
+    let mut pen = Pen::new();
+    let width = current.width();
+    let (mut previous_style, mut style) = (Style::new(), Style::new());
+    let (mut previous_glyphs, mut glyphs) = (Glyphs::new(width), Glyphs::new(width));
+
+    for (idx, (stored, cell)) in previous.cells().zip(current.cells()).enumerate() {
+        previous_style = stored.style.inherits(previous_style);
+        style = cell.style.inherits(style);
+
+        let previous_glyph = previous_glyphs.next(stored);
+        let glyph = glyphs.next(cell);
+
+        if !changed((previous_glyph, previous_style), (glyph, style)) {
+            continue;
+        }
+
+        if let Some(character) = glyph {
+            pen.write_at(writer, (idx % width, idx / width), character, style)?;
+        }
+    }
+
+    pen.finish(writer)
+}
+
+/// Returns whether a cell looks different between two frames.
+///
+/// Cells that are covered by a wide character are not displayed, so their style doesn't matter.
+/// If a cell has become covered, the wide character covering it has changed too, and redrawing it
+/// will take care of the covered cell.
+fn changed(before: (Option<char>, Style), after: (Option<char>, Style)) -> bool {
+    match (before, after) {
+        ((None, _), (None, _)) => false,
+        (before, after) => before != after,
+    }
+}
+
+/// Keeps track of the cursor's position and the active SGR attributes while writing cells.
+struct Pen {
+    /// Where the cursor is after the last write. `None` if it is unknown.
+    cursor: Option<(usize, usize)>,
+    sgr: SgrState,
+}
+
+impl Pen {
+    const fn new() -> Self {
+        Self {
+            cursor: None,
+            sgr: SgrState::new(),
+        }
+    }
+
+    /// Writes a displayed character at the given position using a resolved [`Style`], only moving the cursor
+    /// if it isn't already there. Runs of changed cells on the same row only need a single cursor movement.
+    fn write_at(
+        &mut self,
+        writer: &mut impl Write,
+        (x, y): (usize, usize),
+        character: char,
+        style: Style,
+    ) -> crate::Result<()> {
+        if self.cursor != Some((x, y)) {
+            // ANSI coordinates are 1-indexed.
+            write!(writer, "\x1b[{};{}H", y + 1, x + 1).map_err(|e| anyhow!(e))?;
+        }
+
+        write!(writer, "{}{character}", self.sgr.transition(style)).map_err(|e| anyhow!(e))?;
+
+        self.cursor = Some((x + width::of_char(character), y));
+
+        Ok(())
+    }
+
+    /// Leaves the terminal with its default attributes, so that the next frame knows what state it
+    /// starts in.
+    fn finish(mut self, writer: &mut impl Write) -> crate::Result<()> {
+        writer.write_str(self.sgr.reset()).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+}
+
+/// Writes every cell of the terminal, regardless of what was there before.
+fn write_full(writer: &mut impl Write, terminal: &impl TerminalConst) -> crate::Result<()> {
+    let mut pen = Pen::new();
+    let mut style = Style::new();
+    let width = terminal.width();
+    let mut glyphs = Glyphs::new(width);
+
+    // Nothing is known about the state of the terminal before a full redraw.
+    writer.write_str(SgrState::RESET).map_err(|e| anyhow!(e))?;
+
+    for (idx, cell) in terminal.cells().enumerate() {
+        style = cell.style.inherits(style);
+
+        if let Some(character) = glyphs.next(cell) {
+            pen.write_at(writer, (idx % width, idx / width), character, style)?;
+        }
+    }
+
+    pen.finish(writer)
+}
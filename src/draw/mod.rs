@@ -0,0 +1,257 @@
+//! # The draw module is responsible for the traits that let you draw to the screen
+//!
+//! Tuit itself does not handle I/O by itself. This means that Tuit users are left responsible
+//! for rendering the terminal as well as handling input and output.
+//!
+//! ## Example
+//! ```compile_fail
+//! use tuit::errors::Error;
+//! use tuit::prelude::*;
+//!
+//! #[derive(GpuMagic)]
+//! struct MyGPU {}
+//!
+//! impl Renderer for MyGPU {
+//!     fn render(&mut self, terminal: impl TerminalConst) -> tuit::Result<()> {
+//!         for character in terminal.cells() {
+//!             self.gpu_magic(character)
+//!         }
+//!
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+#[cfg(feature = "ansi_renderer")]
+pub use damage::{render_diff, DiffRenderer};
+
+#[cfg(feature = "ansi_renderer")]
+use core::fmt::{Display, Formatter, Write};
+#[cfg(feature = "ansi_renderer")]
+use anyhow::anyhow;
+#[cfg(feature = "ansi_renderer")]
+use crate::draw::ansi::SgrState;
+#[cfg(feature = "ansi_renderer")]
+use crate::style::Style;
+#[cfg(feature = "ansi_renderer")]
+use crate::terminal::Cell;
+use crate::terminal::TerminalConst;
+#[cfg(feature = "ansi_renderer")]
+use crate::terminal::width;
+
+/// The code for the [`DiffRenderer`], which only redraws the cells that have changed.
+#[cfg(feature = "ansi_renderer")]
+pub mod damage;
+/// The code for the [`ansi::SgrState`], which keeps ANSI styling output minimal.
+#[cfg(feature = "ansi_renderer")]
+pub mod ansi;
+
+/// This trait is written by the implementor and is responsible for rendering the terminal's data
+/// to the screen.
+///
+/// It only has one method, which is [`Renderer::render`].
+///
+/// The method receives a reference to a type that implements the [`TerminalConst`] trait, and uses the data within to render the terminal.
+///
+/// ```feature,stdout_render
+/// use tuit::draw::Target;
+/// use tuit::terminal::ConstantSize;
+///
+/// let my_terminal = ConstantSize::<20, 20>::new();
+/// let mut stdout = std::io::stdout();
+///
+/// stdout.render(&my_terminal).expect("Failed to draw to stdout");
+/// ```
+///
+pub trait Renderer {
+    /// This method gets called when the implementor calls [`TerminalConst::display`]
+    ///
+    /// ## Dummy render implementation:
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::Cell;
+    /// struct MyGPU;
+    ///
+    /// impl MyGPU {
+    ///     pub fn gpu_magic(&mut self, character: &Cell) {
+    ///         // Huzzah!
+    ///     }
+    /// }
+    ///
+    /// impl Renderer for MyGPU {
+    ///     fn render(&mut self, terminal: impl TerminalConst) -> tuit::Result<()> {
+    ///         let cells = terminal.cells();
+    ///
+    ///         // Do some magic to render characters!
+    ///         for cell in cells {
+    ///             self.gpu_magic(cell)
+    ///         }
+    ///
+    ///         return Ok(());
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// When you implement [`Renderer`], you can return an [`Err`] that will help you better cope
+    /// with render failures and may help with debugging.
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()>;
+}
+
+/// Doesn't really do anything when [`Renderer::render`] is called. I mean... what would you
+/// expect a struct called [`DummyTarget`] to accomplish? World peace?
+pub struct DummyTarget;
+
+impl Renderer for DummyTarget {
+    fn render(&mut self, _terminal: impl TerminalConst) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ansi_renderer")]
+/// A [`Renderer`] that takes in a writer and outputs ANSI escape codes to it to use for formatting.
+pub struct AnsiRenderer<T>(pub T);
+
+#[cfg(feature = "ansi_renderer")]
+impl<T: Write> Renderer for AnsiRenderer<T> {
+    fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
+        write!(self.0, "{}", AnsiFrame(terminal)).map_err(|e| anyhow!(e))?;
+
+        Ok(())
+    }
+}
+
+/// Formats an entire terminal as ANSI text, with one line per row.
+///
+/// This is shared between the [`AnsiRenderer`] and the
+/// [`StdoutRenderer`](crate::std::stdout_render::StdoutRenderer), which write to different kinds
+/// of writers.
+#[cfg(feature = "ansi_renderer")]
+pub(crate) struct AnsiFrame<T>(pub T);
+
+#[cfg(feature = "ansi_renderer")]
+impl<T: TerminalConst> Display for AnsiFrame<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let terminal_width = self.0.width();
+        let mut sgr = SgrState::new();
+        let mut style = Style::new();
+
+        // Nothing is known about the terminal's attributes before we start writing.
+        f.write_str(SgrState::RESET)?;
+
+        let mut glyphs = Glyphs::new(terminal_width);
+
+        for (idx, character_cell) in self.0.cells().enumerate() {
+            if idx % terminal_width == 0 {
+                // Resetting before the line break stops background colours from bleeding into
+                // the rest of the line.
+                f.write_str(sgr.reset())?;
+                writeln!(f)?;
+            }
+
+            // A `None` in the cell's style means that it should use the preceding cell's value.
+            style = character_cell.style.inherits(style);
+
+            let Some(character) = glyphs.next(character_cell) else {
+                // Covered by the wide character before it.
+                continue;
+            };
+
+            write!(f, "{}{character}", sgr.transition(style))?;
+        }
+
+        f.write_str(sgr.reset())
+    }
+}
+
+/// Works out which character is actually displayed for each [`Cell`] in a row, so that every row
+/// takes up exactly as many columns as the terminal is wide.
+///
+/// Cells must be passed to [`Glyphs::next`] in row-major order.
+#[cfg(feature = "ansi_renderer")]
+pub(crate) struct Glyphs {
+    row_width: usize,
+    column: usize,
+    /// Whether the next cell is covered by a wide character.
+    covered: bool,
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl Glyphs {
+    pub(crate) const fn new(row_width: usize) -> Self {
+        Self {
+            row_width,
+            column: 0,
+            covered: false,
+        }
+    }
+
+    /// Returns the character to display for the next cell, or `None` if the cell is covered by the
+    /// wide character before it and nothing should be displayed.
+    pub(crate) fn next(&mut self, cell: &Cell) -> Option<char> {
+        let column = self.column;
+
+        self.column = (self.column + 1) % self.row_width;
+
+        if core::mem::replace(&mut self.covered, false) {
+            return None;
+        }
+
+        let character = printable(cell.character);
+
+        if width::of_char(character) == 2 {
+            // A wide character in the last column would spill over onto the next row.
+            if column + 1 >= self.row_width {
+                return Some(' ');
+            }
+
+            self.covered = true;
+        }
+
+        Some(character)
+    }
+}
+
+/// Protect against alignment issues that can arise from characters like `\0` or `\t`, combining marks,
+/// or [`Cell::CONTINUATION`]s that no longer follow a wide character, by replacing them with a space.
+#[cfg(feature = "ansi_renderer")]
+pub(crate) fn printable(character: char) -> char {
+    if character.is_whitespace() || character.is_control() || width::of_char(character) == 0 {
+        ' '
+    } else {
+        character
+    }
+}
+
+#[cfg(feature = "ansi_renderer")]
+impl core::fmt::Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        use owo_colors::OwoColorize;
+
+        let owo_style: owo_colors::Style = self.style.into();
+
+        write!(f, "{}", self.character.style(owo_style))
+    }
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "ansi_renderer"))]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::{AnsiFrame, SgrState};
+    use crate::terminal::ConstantSize;
+
+    #[test]
+    fn frame_resets_once() {
+        let terminal: ConstantSize<2, 1> = ConstantSize::new();
+        let frame = format!("{}", AnsiFrame(&terminal));
+
+        assert!(frame.starts_with(SgrState::RESET));
+        assert!(!frame[SgrState::RESET.len()..].starts_with(SgrState::RESET), "The frame should only reset once at the start: {frame:?}");
+    }
+}
@@ -85,6 +85,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn empty_rects() {
+        let rectangle = Rectangle::of_size((20, 20));
+
+        assert!(rectangle.contains_rect(Rectangle::of_size((0, 0))));
+        assert!(rectangle.contains_rect(Rectangle::new((20, 0), (20, 20))));
+        assert!(rectangle.contains_rect(Rectangle::new((0, 20), (20, 20))));
+        assert!(!rectangle.contains_rect(Rectangle::new((21, 0), (21, 20))));
+
+        let terminal: ConstantSize<20, 20> = ConstantSize::new();
+        let view = terminal.view(Rectangle::new((20, 0), (20, 20))).expect("Empty views on the edge should be valid");
+
+        assert_eq!(view.cells().count(), 0);
+    }
+
     #[test]
     fn centered_empty() {
         use crate::widgets::builtins::dummy::Dummy;
@@ -4,7 +4,7 @@ use std::io::Write;
 
 use anyhow::anyhow;
 
-use crate::draw::Renderer;
+use crate::draw::{AnsiFrame, Renderer};
 use crate::terminal::TerminalConst;
 
 /// Use [`StdoutRenderer::default`] to create a new [`StdoutRenderer`].
@@ -35,27 +35,7 @@ impl Default for StdoutRenderer {
 // This is why we have to do... this. :(
 impl Renderer for StdoutRenderer {
     fn render(&mut self, terminal: impl TerminalConst) -> crate::Result<()> {
-        let terminal_width = terminal.width();
-
-        let characters = terminal.cells();
-
-        for (idx, character_cell) in characters.enumerate() {
-            if idx % terminal_width == 0 {
-                writeln!(self.0).map_err(|e| anyhow!(e))?;
-            }
-
-            let mut character_cell = *character_cell;
-
-            // Protect against alignment issues that can arise from characters
-            // like `\0` or `\t` by replacing them with a space.
-            //
-            // FIXME: Wide characters not handled.
-            if character_cell.character.is_whitespace() || character_cell.character.is_control() {
-                character_cell.character = ' ';
-            }
-
-            write!(self.0, "{character_cell}").map_err(|e| anyhow!(e))?;
-        }
+        write!(self.0, "{}", AnsiFrame(terminal)).map_err(|e| anyhow!(e))?;
 
         self.0.flush()?;
 
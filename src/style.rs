@@ -1,7 +1,7 @@
 //! All the important types for styling in Tuit. Contains structs like `Colour` and `Style`.
 
 /// Represents a 4-bit ANSI terminal colour.
-/// <br /> <br />
+///
 /// Usually, two of these are used in a terminal to create an 8-bit colour consisting
 /// of a foreground and a background.
 /// <br /> <br />
@@ -73,6 +73,7 @@ pub enum Colour {
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
 /// This struct contains a cell's styling data.
+///
 /// If a field is set to none, it will use the data from the last cell in the terminal that had it set.
 /// If a field is None for all cells, then it will assume the terminal default style.
 ///
@@ -400,4 +401,40 @@ impl Style {
             invert: or!(self.invert, fallback.invert),
         }
     }
+
+    /// Returns a [`Style`] that sets every property that is set in `self` back to its default, and
+    /// leaves everything else unset.
+    ///
+    /// Unset properties inherit from the cell that they're drawn over, so a highlight (like a cursor)
+    /// would otherwise stick to a cell after it moves away. Inheriting from the reset highlight
+    /// prevents that.
+    ///
+    /// ```
+    /// use tuit::style::{Ansi4, Colour, Style};
+    ///
+    /// let highlight = Style::new().bg_ansi4(Ansi4::Blue).inverted();
+    /// let text = Style::new().fg_ansi4(Ansi4::Red).inherits(highlight.reset());
+    ///
+    /// assert_eq!(text, Style::new().fg_ansi4(Ansi4::Red).bg(Colour::TerminalDefault).not_inverted());
+    /// ```
+    #[must_use]
+    pub const fn reset(self) -> Self {
+        // Same as above, because `Option::map` can't be used in a const context either.
+        macro_rules! reset {
+            ($property: expr, $default: expr) => {
+                match $property {
+                    Some(_) => Some($default),
+                    None => None,
+                }
+            };
+        }
+
+        Self {
+            fg_colour: reset!(self.fg_colour, Colour::TerminalDefault),
+            bg_colour: reset!(self.bg_colour, Colour::TerminalDefault),
+            font_weight: reset!(self.font_weight, 400),
+            underline: reset!(self.underline, false),
+            invert: reset!(self.invert, false),
+        }
+    }
 }
\ No newline at end of file
@@ -52,9 +52,11 @@ impl<T: Terminal, D: Renderer> TerminalMut for Debug<T, D> {
         use crate::style::Colour;
         use crate::style::Ansi4;
 
+        #[allow(clippy::manual_inspect)]
         self
             .terminal
             .cells_mut()
+            // `inspect` only hands out a shared reference, so it can't be used to mutate the cell.
             .map(|cell| {
                 cell.style = cell.style.bg(Colour::Ansi16(Ansi4::Red));
                 cell
@@ -0,0 +1,675 @@
+use crate::terminal::{Key, KeyState, Modifiers, MouseButton, ScrollDirection, UpdateInfo};
+
+/// The escape byte that starts every escape sequence.
+const ESC: u8 = 0x1b;
+
+/// The maximum number of numeric parameters kept from a single control sequence. Any further
+/// parameters are ignored.
+const MAX_PARAMS: usize = 4;
+
+/// Bits of an SGR mouse report's button code.
+mod mouse {
+    /// The low bits that select the button.
+    pub const BUTTON: u16 = 0b11;
+    /// Set when the mouse moved instead of a button being pressed.
+    pub const MOTION: u16 = 32;
+    /// Set for the scroll wheel.
+    pub const WHEEL: u16 = 64;
+    /// Set for the extra (side) buttons.
+    pub const EXTRA: u16 = 128;
+    /// Set when Shift is held.
+    pub const SHIFT: u16 = 4;
+    /// Set when Alt (Meta) is held.
+    pub const ALT: u16 = 8;
+    /// Set when Ctrl is held.
+    pub const CTRL: u16 = 16;
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+enum State {
+    /// Not inside of any sequence.
+    #[default]
+    Ground,
+    /// An [`ESC`] byte has been received.
+    Escape,
+    /// Inside of a control sequence (`ESC [`).
+    Csi,
+    /// Inside of a single shift sequence (`ESC O`), which some terminals use for the arrow and function keys.
+    Ss3,
+    /// Inside of a multibyte UTF-8 character.
+    Utf8,
+}
+
+/// An incremental decoder that turns the bytes sent by a VT100/xterm-compatible terminal into [`UpdateInfo`]s.
+///
+/// Bytes are fed to the decoder one at a time using [`AnsiDecoder::feed`], which returns an [`UpdateInfo`]
+/// whenever a complete key press or mouse click has been decoded. The decoder does not allocate, so it
+/// works in `no_std` environments too.
+///
+/// - Printable characters (including multibyte UTF-8 ones) are reported as [`UpdateInfo::KeyboardCharacter`].
+/// - Enter, Tab, Backspace, Escape, the arrow keys, the navigation keys and F1 through F12 are reported as
+///   [`UpdateInfo::KeyboardInput`].
+/// - Keys pressed along with modifier keys, like `Ctrl+A` or `Shift+Tab`, are reported as
+///   [`UpdateInfo::ModifiedKeyboardInput`], as far as the terminal tells them apart.
+/// - Characters typed while holding Alt are reported as [`UpdateInfo::ModifiedKeyboardCharacter`].
+/// - Mouse presses are reported as [`UpdateInfo::CellClicked`], with 0-based coordinates, or as
+///   [`UpdateInfo::ModifiedCellClicked`] if modifier keys are held. Releases, drags, movement and the
+///   scroll wheel are reported as [`UpdateInfo::MouseReleased`], [`UpdateInfo::MouseDragged`],
+///   [`UpdateInfo::MouseMoved`] and [`UpdateInfo::MouseScrolled`]. The terminal must be using SGR mouse
+///   reports, which can be enabled by writing `"\x1b[?1003h\x1b[?1006h"` (or `?1000h` to only report
+///   presses, releases and scrolling).
+/// - Text pasted while bracketed paste is enabled (`"\x1b[?2004h"`) is reported character-by-character,
+///   with tabs and line breaks as [`UpdateInfo::KeyboardCharacter`]s instead of key presses.
+///
+/// Every key is reported as [`KeyState::KeyDown`], because terminals don't report key releases.
+///
+/// ```
+/// use tuit::terminal::{AnsiDecoder, Key, KeyState, Modifiers, MouseButton, UpdateInfo};
+///
+/// let mut decoder = AnsiDecoder::new();
+/// let input = "h\x1b[A\x1b[<0;5;3M\x03\x1b[1;5C\x1bf\x1b[<16;1;1M".bytes();
+///
+/// let mut events = input.filter_map(|byte| decoder.feed(byte));
+///
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardCharacter('h', KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardInput(Key::Up, KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::CellClicked(4, 2, MouseButton::Primary)));
+/// assert_eq!(events.next(), Some(UpdateInfo::ModifiedKeyboardInput(Key::C, Modifiers::CTRL, KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::ModifiedKeyboardInput(Key::Right, Modifiers::CTRL, KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::ModifiedKeyboardCharacter('f', Modifiers::ALT, KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::ModifiedCellClicked(0, 0, MouseButton::Primary, Modifiers::CTRL)));
+/// assert_eq!(events.next(), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub struct AnsiDecoder {
+    state: State,
+    /// The numeric parameters of the current control sequence.
+    params: [u16; MAX_PARAMS],
+    /// The number of parameters that the current control sequence has, including ones past [`MAX_PARAMS`].
+    param_count: usize,
+    /// The private marker of the current control sequence (i.e. the `<` in SGR mouse reports).
+    private: Option<u8>,
+    /// The bytes of the current multibyte UTF-8 character.
+    utf8: [u8; 4],
+    utf8_len: usize,
+    /// Whether the current character was preceded by an escape, which is how terminals send Alt.
+    alt: bool,
+    pasting: bool,
+}
+
+impl AnsiDecoder {
+    /// Create a new [`AnsiDecoder`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: State::Ground,
+            params: [0; MAX_PARAMS],
+            param_count: 0,
+            private: None,
+            utf8: [0; 4],
+            utf8_len: 0,
+            alt: false,
+            pasting: false,
+        }
+    }
+
+    /// Returns whether the decoder is in the middle of a bracketed paste.
+    ///
+    /// ```
+    /// use tuit::terminal::{AnsiDecoder, Key, KeyState, UpdateInfo};
+    ///
+    /// let mut decoder = AnsiDecoder::new();
+    ///
+    /// for byte in "\x1b[200~".bytes() {
+    ///     assert_eq!(decoder.feed(byte), None);
+    /// }
+    ///
+    /// assert!(decoder.is_pasting());
+    /// // Inside of a paste, line breaks are part of the text instead of Enter key presses.
+    /// assert_eq!(decoder.feed(b'\r'), Some(UpdateInfo::KeyboardCharacter('\n', KeyState::KeyDown)));
+    ///
+    /// for byte in "\x1b[201~".bytes() {
+    ///     assert_eq!(decoder.feed(byte), None);
+    /// }
+    ///
+    /// assert!(!decoder.is_pasting());
+    /// assert_eq!(decoder.feed(b'\r'), Some(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown)));
+    /// ```
+    #[must_use]
+    pub const fn is_pasting(&self) -> bool {
+        self.pasting
+    }
+
+    /// Feed the next byte read from the terminal into the decoder.
+    ///
+    /// Returns `None` if the byte doesn't complete an event, or if the event it completes isn't one that
+    /// can be represented as an [`UpdateInfo`].
+    ///
+    /// ```
+    /// use tuit::terminal::{AnsiDecoder, KeyState, UpdateInfo};
+    ///
+    /// let mut decoder = AnsiDecoder::new();
+    /// let [first, second, third] = "日".as_bytes().try_into().unwrap();
+    ///
+    /// assert_eq!(decoder.feed(first), None);
+    /// assert_eq!(decoder.feed(second), None);
+    /// assert_eq!(decoder.feed(third), Some(UpdateInfo::KeyboardCharacter('日', KeyState::KeyDown)));
+    /// ```
+    ///
+    /// ## Mouse events
+    ///
+    /// ```
+    /// use tuit::terminal::{AnsiDecoder, MouseButton, ScrollDirection, UpdateInfo};
+    ///
+    /// let mut decoder = AnsiDecoder::new();
+    /// // Press, drag, release, move and scroll.
+    /// let input = "\x1b[<0;1;1M\x1b[<32;2;1M\x1b[<0;2;1m\x1b[<35;3;4M\x1b[<65;3;4M".bytes();
+    ///
+    /// let mut events = input.filter_map(|byte| decoder.feed(byte));
+    ///
+    /// assert_eq!(events.next(), Some(UpdateInfo::CellClicked(0, 0, MouseButton::Primary)));
+    /// assert_eq!(events.next(), Some(UpdateInfo::MouseDragged(1, 0, MouseButton::Primary)));
+    /// assert_eq!(events.next(), Some(UpdateInfo::MouseReleased(1, 0, MouseButton::Primary)));
+    /// assert_eq!(events.next(), Some(UpdateInfo::MouseMoved(2, 3)));
+    /// assert_eq!(events.next(), Some(UpdateInfo::MouseScrolled(2, 3, ScrollDirection::Down)));
+    /// assert_eq!(events.next(), None);
+    /// ```
+    pub fn feed(&mut self, byte: u8) -> Option<UpdateInfo> {
+        match self.state {
+            State::Ground => self.ground(byte),
+            State::Escape => self.escape(byte),
+            State::Csi => self.csi(byte),
+            State::Ss3 => {
+                self.state = State::Ground;
+
+                match byte {
+                    b'M' => Some(key(Key::Enter)),
+                    _ => final_key(byte).map(key),
+                }
+            }
+            State::Utf8 => self.utf8(byte),
+        }
+    }
+
+    /// Tell the decoder that no more bytes are available right now.
+    ///
+    /// The Escape key sends a lone escape (`0x1b`) byte, which can't be told apart from the start of an escape
+    /// sequence until more bytes arrive. Call this once reading from the terminal times out to report it.
+    /// Any other unfinished sequence is discarded.
+    ///
+    /// ```
+    /// use tuit::terminal::{AnsiDecoder, Key, KeyState, UpdateInfo};
+    ///
+    /// let mut decoder = AnsiDecoder::new();
+    ///
+    /// assert_eq!(decoder.feed(0x1b), None);
+    /// assert_eq!(decoder.flush(), Some(UpdateInfo::KeyboardInput(Key::Escape, KeyState::KeyDown)));
+    /// assert_eq!(decoder.flush(), None);
+    /// ```
+    pub fn flush(&mut self) -> Option<UpdateInfo> {
+        let state = core::mem::take(&mut self.state);
+
+        (state == State::Escape).then(|| key(Key::Escape))
+    }
+
+    const fn ground(&mut self, byte: u8) -> Option<UpdateInfo> {
+        match byte {
+            ESC => {
+                self.state = State::Escape;
+
+                None
+            }
+            b'\t' if self.pasting => Some(character('\t')),
+            b'\r' | b'\n' if self.pasting => Some(character('\n')),
+            b'\r' | b'\n' => Some(key(Key::Enter)),
+            b'\t' => Some(key(Key::Tab)),
+            0x08 | 0x7f => Some(key(Key::Backspace)),
+            // Ctrl+A through Ctrl+Z.
+            0x01..=0x1a => Some(modified(Key::from_code(Key::A.code() + byte - 1), Modifiers::CTRL)),
+            0x20..=0x7e => Some(character(byte as char)),
+            0xc2..=0xf4 => {
+                self.utf8[0] = byte;
+                self.utf8_len = 1;
+                self.state = State::Utf8;
+
+                None
+            }
+            _ => None,
+        }
+    }
+
+    const fn escape(&mut self, byte: u8) -> Option<UpdateInfo> {
+        match byte {
+            b'[' => {
+                self.params = [0; MAX_PARAMS];
+                self.param_count = 0;
+                self.private = None;
+                self.state = State::Csi;
+
+                None
+            }
+            b'O' => {
+                self.state = State::Ss3;
+
+                None
+            }
+            // The first escape was a lone Escape key press; the second one may start a sequence.
+            ESC => Some(key(Key::Escape)),
+            // Terminals send Alt+<key> as an escape followed by the key.
+            _ => {
+                self.state = State::Ground;
+
+                let event = self.ground(byte);
+
+                // Multibyte characters only get reported once they're complete.
+                self.alt = matches!(self.state, State::Utf8);
+
+                match event {
+                    Some(event) => Some(event.with_modifiers(Modifiers::ALT)),
+                    None => None,
+                }
+            }
+        }
+    }
+
+    fn csi(&mut self, byte: u8) -> Option<UpdateInfo> {
+        match byte {
+            b'0'..=b'9' => {
+                self.param_count = self.param_count.max(1);
+
+                if let Some(param) = self.params.get_mut(self.param_count - 1) {
+                    *param = param.saturating_mul(10).saturating_add(u16::from(byte - b'0'));
+                }
+
+                None
+            }
+            b':' | b';' => {
+                self.param_count = self.param_count.max(1).saturating_add(1);
+
+                None
+            }
+            b'<'..=b'?' => {
+                self.private = Some(byte);
+
+                None
+            }
+            // Intermediate bytes; none of the sequences we decode use them.
+            0x20..=0x2f => None,
+            0x40..=0x7e => {
+                self.state = State::Ground;
+
+                self.csi_final(byte)
+            }
+            // Not valid inside of a control sequence, so abandon it.
+            _ => {
+                self.state = State::Ground;
+
+                self.ground(byte)
+            }
+        }
+    }
+
+    fn csi_final(&mut self, byte: u8) -> Option<UpdateInfo> {
+        match (self.private, byte) {
+            (Some(b'<'), b'M' | b'm') => self.mouse(byte),
+            (Some(_), _) => None,
+            (None, b'~') => self.tilde(),
+            // Shift+Tab.
+            (None, b'Z') => Some(modified(Key::Tab, Modifiers::SHIFT)),
+            (None, _) => Some(modified(final_key(byte)?, self.modifiers())),
+        }
+    }
+
+    /// Decodes `ESC [ <n> ~` sequences, which are used for the navigation keys, most function keys
+    /// and bracketed paste.
+    fn tilde(&mut self) -> Option<UpdateInfo> {
+        let key = match self.param(0)? {
+            1 | 7 => Key::Home,
+            2 => Key::Insert,
+            3 => Key::Delete,
+            4 | 8 => Key::End,
+            5 => Key::PageUp,
+            6 => Key::PageDown,
+            11 => Key::F1,
+            12 => Key::F2,
+            13 => Key::F3,
+            14 => Key::F4,
+            15 => Key::F5,
+            17 => Key::F6,
+            18 => Key::F7,
+            19 => Key::F8,
+            20 => Key::F9,
+            21 => Key::F10,
+            23 => Key::F11,
+            24 => Key::F12,
+            200 => {
+                self.pasting = true;
+
+                return None;
+            }
+            201 => {
+                self.pasting = false;
+
+                return None;
+            }
+            _ => return None,
+        };
+
+        Some(modified(key, self.modifiers()))
+    }
+
+    /// Returns the [`Modifiers`] of the current control sequence. Terminals send them as the second
+    /// parameter, plus one, for keys like `Ctrl+Up` (`ESC [ 1 ; 5 A`).
+    fn modifiers(&self) -> Modifiers {
+        match self.param(1) {
+            #[allow(clippy::cast_possible_truncation)] // Deliberately discards the unknown bits.
+            Some(modifiers @ 1..) => Modifiers::from_bits((modifiers - 1) as u8),
+            _ => Modifiers::NONE,
+        }
+    }
+
+    /// Decodes an SGR mouse report (`ESC [ < <button> ; <x> ; <y> M`, or `m` for releases).
+    ///
+    /// The middle button and the side buttons are reported as [`MouseButton::AuxiliaryButton`]s using
+    /// X11's button numbering, so the middle button is `2` and the side buttons start at `8`.
+    fn mouse(&self, final_byte: u8) -> Option<UpdateInfo> {
+        let (code, x, y) = (self.param(0)?, self.param(1)?, self.param(2)?);
+
+        // SGR mouse reports are 1-based.
+        let x = usize::from(x).saturating_sub(1);
+        let y = usize::from(y).saturating_sub(1);
+
+        if code & mouse::WHEEL != 0 {
+            let direction = match code & mouse::BUTTON {
+                0 => ScrollDirection::Up,
+                1 => ScrollDirection::Down,
+                2 => ScrollDirection::Left,
+                _ => ScrollDirection::Right,
+            };
+
+            return Some(UpdateInfo::MouseScrolled(x, y, direction));
+        }
+
+        let button = match (code & mouse::EXTRA != 0, code & mouse::BUTTON) {
+            (true, button) => MouseButton::AuxiliaryButton(8 + button),
+            (false, 0) => MouseButton::Primary,
+            (false, 1) => MouseButton::AuxiliaryButton(2),
+            (false, 2) => MouseButton::Secondary,
+            // Motion with no buttons held down.
+            (false, _) if code & mouse::MOTION != 0 => return Some(UpdateInfo::MouseMoved(x, y)),
+            (false, _) => return None,
+        };
+
+        if final_byte == b'm' {
+            return Some(UpdateInfo::MouseReleased(x, y, button));
+        }
+
+        if code & mouse::MOTION != 0 {
+            return Some(UpdateInfo::MouseDragged(x, y, button));
+        }
+
+        let mut modifiers = Modifiers::NONE;
+
+        for (bit, modifier) in [
+            (mouse::SHIFT, Modifiers::SHIFT),
+            (mouse::ALT, Modifiers::ALT),
+            (mouse::CTRL, Modifiers::CTRL),
+        ] {
+            if code & bit != 0 {
+                modifiers |= modifier;
+            }
+        }
+
+        Some(UpdateInfo::CellClicked(x, y, button).with_modifiers(modifiers))
+    }
+
+    fn utf8(&mut self, byte: u8) -> Option<UpdateInfo> {
+        // The character was cut short by a byte that isn't a continuation byte.
+        if byte & 0xc0 != 0x80 {
+            self.state = State::Ground;
+            self.alt = false;
+
+            return self.ground(byte);
+        }
+
+        self.utf8[self.utf8_len] = byte;
+        self.utf8_len += 1;
+
+        let expected = match self.utf8[0] {
+            0xc2..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+
+        if self.utf8_len < expected {
+            return None;
+        }
+
+        self.state = State::Ground;
+
+        let modifiers = if core::mem::take(&mut self.alt) {
+            Modifiers::ALT
+        } else {
+            Modifiers::NONE
+        };
+
+        // Overlong encodings and surrogates get rejected here.
+        let decoded = core::str::from_utf8(&self.utf8[..expected]).ok()?;
+
+        decoded
+            .chars()
+            .next()
+            .map(|decoded| character(decoded).with_modifiers(modifiers))
+    }
+
+    /// Returns the parameter at `idx` of the current control sequence, if it was given.
+    fn param(&self, idx: usize) -> Option<u16> {
+        if idx < self.param_count {
+            self.params.get(idx).copied()
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the [`Key`] that a control sequence's final byte stands for, for the keys that are sent as
+/// `ESC [ <final>` or `ESC O <final>`.
+const fn final_key(byte: u8) -> Option<Key> {
+    let code = match byte {
+        b'A' => Key::Up,
+        b'B' => Key::Down,
+        b'C' => Key::Right,
+        b'D' => Key::Left,
+        b'H' => Key::Home,
+        b'F' => Key::End,
+        b'P' => Key::F1,
+        b'Q' => Key::F2,
+        b'R' => Key::F3,
+        b'S' => Key::F4,
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+const fn key(key: Key) -> UpdateInfo {
+    UpdateInfo::KeyboardInput(key, KeyState::KeyDown)
+}
+
+const fn modified(key: Key, modifiers: Modifiers) -> UpdateInfo {
+    UpdateInfo::KeyboardInput(key, KeyState::KeyDown).with_modifiers(modifiers)
+}
+
+const fn character(character: char) -> UpdateInfo {
+    UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown)
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::AnsiDecoder;
+    use crate::terminal::{Key, KeyState, Modifiers, MouseButton, ScrollDirection, UpdateInfo};
+
+    /// Feeds every byte into a fresh decoder and collects the events that come out.
+    fn decode(bytes: &[u8]) -> Vec<UpdateInfo> {
+        let mut decoder = AnsiDecoder::new();
+
+        bytes.iter().filter_map(|&byte| decoder.feed(byte)).collect()
+    }
+
+    fn key(key: Key) -> UpdateInfo {
+        UpdateInfo::KeyboardInput(key, KeyState::KeyDown)
+    }
+
+    fn modified(key: Key, modifiers: Modifiers) -> UpdateInfo {
+        UpdateInfo::ModifiedKeyboardInput(key, modifiers, KeyState::KeyDown)
+    }
+
+    fn character(character: char) -> UpdateInfo {
+        UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown)
+    }
+
+    #[test]
+    fn control_characters() {
+        assert_eq!(
+            decode(b"\r\n\t\x7f\x08\x01\x1a"),
+            [
+                key(Key::Enter),
+                key(Key::Enter),
+                key(Key::Tab),
+                key(Key::Backspace),
+                key(Key::Backspace),
+                modified(Key::A, Modifiers::CTRL),
+                modified(Key::Z, Modifiers::CTRL),
+            ]
+        );
+    }
+
+    #[test]
+    fn utf8_characters() {
+        assert_eq!(
+            decode("aé日🦀".as_bytes()),
+            [character('a'), character('é'), character('日'), character('🦀')]
+        );
+    }
+
+    #[test]
+    fn ss3_keys() {
+        assert_eq!(
+            decode(b"\x1bOA\x1bOD\x1bOP\x1bOS\x1bOM"),
+            [key(Key::Up), key(Key::Left), key(Key::F1), key(Key::F4), key(Key::Enter)]
+        );
+    }
+
+    #[test]
+    fn tilde_keys() {
+        assert_eq!(
+            decode(b"\x1b[1~\x1b[2~\x1b[3~\x1b[4~\x1b[5~\x1b[6~\x1b[15~\x1b[24~"),
+            [
+                key(Key::Home),
+                key(Key::Insert),
+                key(Key::Delete),
+                key(Key::End),
+                key(Key::PageUp),
+                key(Key::PageDown),
+                key(Key::F5),
+                key(Key::F12),
+            ]
+        );
+    }
+
+    #[test]
+    fn modified_keys() {
+        assert_eq!(
+            decode(b"\x1b[Z\x1b[1;2A\x1b[3;5~\x1b[1;3H"),
+            [
+                modified(Key::Tab, Modifiers::SHIFT),
+                modified(Key::Up, Modifiers::SHIFT),
+                modified(Key::Delete, Modifiers::CTRL),
+                modified(Key::Home, Modifiers::ALT),
+            ]
+        );
+    }
+
+    #[test]
+    fn alt_characters() {
+        assert_eq!(
+            decode("\x1bx\x1bé".as_bytes()),
+            [
+                UpdateInfo::ModifiedKeyboardCharacter('x', Modifiers::ALT, KeyState::KeyDown),
+                UpdateInfo::ModifiedKeyboardCharacter('é', Modifiers::ALT, KeyState::KeyDown),
+            ]
+        );
+    }
+
+    #[test]
+    fn double_escape() {
+        assert_eq!(decode(b"\x1b\x1b"), [key(Key::Escape)]);
+    }
+
+    #[test]
+    fn flushed_escape() {
+        let mut decoder = AnsiDecoder::new();
+
+        assert_eq!(decoder.feed(0x1b), None);
+        assert_eq!(decoder.flush(), Some(key(Key::Escape)));
+        assert_eq!(decoder.flush(), None);
+    }
+
+    #[test]
+    fn mouse_reports() {
+        assert_eq!(
+            decode(b"\x1b[<0;1;1M\x1b[<2;10;4M\x1b[<0;1;1m\x1b[<32;3;2M\x1b[<35;7;8M\x1b[<64;2;2M\x1b[<65;2;2M\x1b[<128;1;1M"),
+            [
+                UpdateInfo::CellClicked(0, 0, MouseButton::Primary),
+                UpdateInfo::CellClicked(9, 3, MouseButton::Secondary),
+                UpdateInfo::MouseReleased(0, 0, MouseButton::Primary),
+                UpdateInfo::MouseDragged(2, 1, MouseButton::Primary),
+                UpdateInfo::MouseMoved(6, 7),
+                UpdateInfo::MouseScrolled(1, 1, ScrollDirection::Up),
+                UpdateInfo::MouseScrolled(1, 1, ScrollDirection::Down),
+                UpdateInfo::CellClicked(0, 0, MouseButton::AuxiliaryButton(8)),
+            ]
+        );
+    }
+
+    #[test]
+    fn modified_mouse_reports() {
+        assert_eq!(
+            decode(b"\x1b[<4;1;1M\x1b[<24;2;2M"),
+            [
+                UpdateInfo::ModifiedCellClicked(0, 0, MouseButton::Primary, Modifiers::SHIFT),
+                UpdateInfo::ModifiedCellClicked(1, 1, MouseButton::Primary, Modifiers::CTRL.union(Modifiers::ALT)),
+            ]
+        );
+    }
+
+    #[test]
+    fn extra_parameters_are_ignored() {
+        assert_eq!(decode(b"\x1b[1;5;1;1;1;1A"), [modified(Key::Up, Modifiers::CTRL)]);
+    }
+
+    #[test]
+    fn unknown_sequences_are_dropped() {
+        assert_eq!(decode(b"\x1b[?25h\x1b[99~a"), [character('a')]);
+    }
+
+    #[test]
+    fn bracketed_paste() {
+        assert_eq!(
+            decode(b"\x1b[200~a\tb\rc\x1b[201~\r"),
+            [
+                character('a'),
+                character('\t'),
+                character('b'),
+                character('\n'),
+                character('c'),
+                key(Key::Enter),
+            ]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+/// Declares the [`Key`] enum along with its conversions to and from USB HID usage IDs, so that the
+/// two can never get out of sync.
+macro_rules! keys {
+    ($($name: ident = $code: literal,)*) => {
+        /// A keyboard key, identified by its USB HID usage ID.
+        ///
+        /// Go to [the UEFI specification](https://uefi.org/specs/UEFI/2.10/Apx_B_Console.html) and
+        /// check for USB keyboard HID values. Usage IDs that don't have a variant of their own are
+        /// represented by [`Key::Other`], so converting a [`u8`] into a [`Key`] and back is lossless.
+        ///
+        /// ```
+        /// use tuit::terminal::Key;
+        ///
+        /// assert_eq!(Key::from(0x28), Key::Enter);
+        /// assert_eq!(u8::from(Key::Up), 0x52);
+        ///
+        /// for code in 0..=u8::MAX {
+        ///     assert_eq!(u8::from(Key::from(code)), code);
+        /// }
+        /// ```
+        #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+        #[non_exhaustive]
+        #[allow(missing_docs)]
+        pub enum Key {
+            $($name,)*
+            /// Any key that doesn't have a variant of its own, by its USB HID usage ID.
+            ///
+            /// Prefer [`Key::from_code`] over constructing this directly, because a usage ID that has
+            /// a variant of its own will not compare equal to that variant.
+            Other(u8),
+        }
+
+        impl Key {
+            /// Returns the [`Key`] with the given USB HID usage ID.
+            #[must_use]
+            pub const fn from_code(code: u8) -> Self {
+                match code {
+                    $($code => Self::$name,)*
+                    _ => Self::Other(code),
+                }
+            }
+
+            /// Returns the USB HID usage ID of the [`Key`].
+            #[must_use]
+            pub const fn code(self) -> u8 {
+                match self {
+                    $(Self::$name => $code,)*
+                    Self::Other(code) => code,
+                }
+            }
+        }
+    };
+}
+
+keys! {
+    A = 0x04,
+    B = 0x05,
+    C = 0x06,
+    D = 0x07,
+    E = 0x08,
+    F = 0x09,
+    G = 0x0A,
+    H = 0x0B,
+    I = 0x0C,
+    J = 0x0D,
+    K = 0x0E,
+    L = 0x0F,
+    M = 0x10,
+    N = 0x11,
+    O = 0x12,
+    P = 0x13,
+    Q = 0x14,
+    R = 0x15,
+    S = 0x16,
+    T = 0x17,
+    U = 0x18,
+    V = 0x19,
+    W = 0x1A,
+    X = 0x1B,
+    Y = 0x1C,
+    Z = 0x1D,
+    Digit1 = 0x1E,
+    Digit2 = 0x1F,
+    Digit3 = 0x20,
+    Digit4 = 0x21,
+    Digit5 = 0x22,
+    Digit6 = 0x23,
+    Digit7 = 0x24,
+    Digit8 = 0x25,
+    Digit9 = 0x26,
+    Digit0 = 0x27,
+    Enter = 0x28,
+    Escape = 0x29,
+    Backspace = 0x2A,
+    Tab = 0x2B,
+    Space = 0x2C,
+    Minus = 0x2D,
+    Equals = 0x2E,
+    LeftBracket = 0x2F,
+    RightBracket = 0x30,
+    Backslash = 0x31,
+    NonUsHash = 0x32,
+    Semicolon = 0x33,
+    Apostrophe = 0x34,
+    Grave = 0x35,
+    Comma = 0x36,
+    Period = 0x37,
+    Slash = 0x38,
+    CapsLock = 0x39,
+    F1 = 0x3A,
+    F2 = 0x3B,
+    F3 = 0x3C,
+    F4 = 0x3D,
+    F5 = 0x3E,
+    F6 = 0x3F,
+    F7 = 0x40,
+    F8 = 0x41,
+    F9 = 0x42,
+    F10 = 0x43,
+    F11 = 0x44,
+    F12 = 0x45,
+    PrintScreen = 0x46,
+    ScrollLock = 0x47,
+    Pause = 0x48,
+    Insert = 0x49,
+    Home = 0x4A,
+    PageUp = 0x4B,
+    Delete = 0x4C,
+    End = 0x4D,
+    PageDown = 0x4E,
+    Right = 0x4F,
+    Left = 0x50,
+    Down = 0x51,
+    Up = 0x52,
+    NumLock = 0x53,
+    KeypadSlash = 0x54,
+    KeypadAsterisk = 0x55,
+    KeypadMinus = 0x56,
+    KeypadPlus = 0x57,
+    KeypadEnter = 0x58,
+    Keypad1 = 0x59,
+    Keypad2 = 0x5A,
+    Keypad3 = 0x5B,
+    Keypad4 = 0x5C,
+    Keypad5 = 0x5D,
+    Keypad6 = 0x5E,
+    Keypad7 = 0x5F,
+    Keypad8 = 0x60,
+    Keypad9 = 0x61,
+    Keypad0 = 0x62,
+    KeypadPeriod = 0x63,
+    NonUsBackslash = 0x64,
+    Menu = 0x65,
+    LeftCtrl = 0xE0,
+    LeftShift = 0xE1,
+    LeftAlt = 0xE2,
+    LeftSuper = 0xE3,
+    RightCtrl = 0xE4,
+    RightShift = 0xE5,
+    RightAlt = 0xE6,
+    RightSuper = 0xE7,
+}
+
+impl Key {
+    /// Returns whether the [`Key`] is one of the modifier keys (Ctrl, Shift, Alt or Super).
+    #[must_use]
+    pub const fn is_modifier(self) -> bool {
+        matches!(self.code(), 0xE0..=0xE7)
+    }
+
+    /// Returns the [`Modifiers`] flag that the [`Key`] sets when held, or [`Modifiers::NONE`] if it
+    /// isn't a modifier key.
+    #[must_use]
+    pub const fn modifier(self) -> Modifiers {
+        match self {
+            Self::LeftCtrl | Self::RightCtrl => Modifiers::CTRL,
+            Self::LeftShift | Self::RightShift => Modifiers::SHIFT,
+            Self::LeftAlt | Self::RightAlt => Modifiers::ALT,
+            Self::LeftSuper | Self::RightSuper => Modifiers::SUPER,
+            _ => Modifiers::NONE,
+        }
+    }
+}
+
+/// The set of modifier keys that were held down during an event.
+///
+/// The flags use the same bits as xterm's modifier parameter, minus one.
+///
+/// ```
+/// use tuit::terminal::Modifiers;
+///
+/// let modifiers = Modifiers::CTRL | Modifiers::SHIFT;
+///
+/// assert!(modifiers.contains(Modifiers::CTRL));
+/// assert!(modifiers.ctrl() && modifiers.shift());
+/// assert!(!modifiers.alt());
+/// ```
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Default)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    /// No modifier keys.
+    pub const NONE: Self = Self(0);
+    /// Either Shift key.
+    pub const SHIFT: Self = Self(0b0001);
+    /// Either Alt key (Option on Mac keyboards).
+    pub const ALT: Self = Self(0b0010);
+    /// Either Ctrl key.
+    pub const CTRL: Self = Self(0b0100);
+    /// Either Super key (the Windows key, or Command on Mac keyboards).
+    pub const SUPER: Self = Self(0b1000);
+
+    /// Create a [`Modifiers`] from its raw bits. Unknown bits are discarded.
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits & 0b1111)
+    }
+
+    /// Returns the raw bits of the [`Modifiers`].
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether no modifier keys are held.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether all the modifiers in `other` are held.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the modifiers held in either `self` or `other`.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns `self` without the modifiers held in `other`.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Returns whether Shift is held.
+    #[must_use]
+    pub const fn shift(self) -> bool {
+        self.contains(Self::SHIFT)
+    }
+
+    /// Returns whether Alt is held.
+    #[must_use]
+    pub const fn alt(self) -> bool {
+        self.contains(Self::ALT)
+    }
+
+    /// Returns whether Ctrl is held.
+    #[must_use]
+    pub const fn ctrl(self) -> bool {
+        self.contains(Self::CTRL)
+    }
+
+    /// Returns whether Super is held.
+    #[must_use]
+    pub const fn super_key(self) -> bool {
+        self.contains(Self::SUPER)
+    }
+}
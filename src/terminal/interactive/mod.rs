@@ -0,0 +1,343 @@
+//! This module is the one containing all the code required for the "interactive" aspects of Tuit.
+//! This includes code like structs for handling input, like [`interactive::MouseButton`] or
+//! [`interactive::KeyState`], as well as the [`UpdateInfo`] and [`UpdateResult`] structs.
+//!
+//! If your input comes from a VT100/xterm-compatible terminal, [`AnsiDecoder`] can turn its raw bytes
+//! into [`UpdateInfo`]s for you. On bare metal, [`ScancodeTranslator`] does the same for PS/2 keyboards.
+
+use core::time::Duration;
+use crate::terminal::Rectangle;
+
+pub use decode::AnsiDecoder;
+pub use keys::{Key, Modifiers};
+pub use ps2::{KeyboardLayout, ScancodeSet, ScancodeTranslator};
+
+/// Code for the [`AnsiDecoder`].
+pub mod decode;
+/// Code for [`Key`]s and [`Modifiers`].
+pub mod keys;
+/// Code for the [`ScancodeTranslator`].
+pub mod ps2;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+/// This enum represents the various buttons on the mouse.
+pub enum MouseButton {
+    /// The primary mouse button, usually the left click button.
+    Primary,
+    /// The secondary mouse button, usually the right click button.
+    Secondary,
+    /// Any auxiliary mouse buttons (for example, additional side buttons).
+    AuxiliaryButton(u16),
+}
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+/// This enum represents the directions that a mouse's scroll wheel can scroll in.
+pub enum ScrollDirection {
+    /// The wheel was scrolled up, away from the user.
+    Up,
+    /// The wheel was scrolled down, towards the user.
+    Down,
+    /// The wheel was tilted (or scrolled horizontally) to the left.
+    Left,
+    /// The wheel was tilted (or scrolled horizontally) to the right.
+    Right,
+}
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+/// [`KeyState`] encompasses the current states that a keyboard key can be in (just pressed, currently held, and just released)
+pub enum KeyState {
+    /// Key has just been released
+    KeyUp = 0,
+    /// Key has just been pressed
+    KeyDown = 1,
+    /// Key is currently held
+    KeyHeld = 2,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+/// `UpdateInfo` encapsulates the information sent after an update
+pub enum UpdateInfo {
+    /// This event triggers when a cell (character) gets clicked. It includes the X co-ordinate,
+    /// Y co-ordinate, and the mouse button that was clicked.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `CellClicked(x_coord, y_coord, mouse_button)`
+    CellClicked(usize, usize, MouseButton),
+    /// This event triggers when a cell gets clicked while modifier keys are held down, like a
+    /// `Ctrl+Click`.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `ModifiedCellClicked(x_coord, y_coord, mouse_button, modifiers)`
+    ModifiedCellClicked(usize, usize, MouseButton, Modifiers),
+    /// This event triggers when a mouse button is released over a cell.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `MouseReleased(x_coord, y_coord, mouse_button)`
+    MouseReleased(usize, usize, MouseButton),
+    /// This event triggers when the mouse moves onto a cell while a button is held down.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `MouseDragged(x_coord, y_coord, mouse_button)`
+    MouseDragged(usize, usize, MouseButton),
+    /// This event triggers when the mouse moves onto a cell while no buttons are held down.
+    /// This can be used for things like hover highlighting.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `MouseMoved(x_coord, y_coord)`
+    MouseMoved(usize, usize),
+    /// This event triggers when the scroll wheel is used while the mouse is over a cell.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `MouseScrolled(x_coord, y_coord, scroll_direction)`
+    MouseScrolled(usize, usize, ScrollDirection),
+    /// This can be sent to widgets to inform them of a printable keyboard key being
+    /// pressed.
+    KeyboardCharacter(char, KeyState),
+    /// This can be sent to widgets to inform them of a printable keyboard key being pressed while
+    /// modifier keys other than Shift are held down, like `Alt+F`.
+    ///
+    /// Shift is usually already accounted for by the character itself (i.e. `'F'` instead of `'f'`).
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `ModifiedKeyboardCharacter(character, modifiers, key_state)`
+    ModifiedKeyboardCharacter(char, Modifiers, KeyState),
+    /// This can be sent to widgets to inform them of a non-printable keyboard key being pressed.
+    ///
+    /// Keys are identified by their USB HID usage ID; see [`Key`].
+    KeyboardInput(Key, KeyState),
+    /// This can be sent to widgets to inform them of a non-printable keyboard key being pressed while
+    /// modifier keys are held down, like `Ctrl+C` or `Shift+Tab`.
+    ///
+    /// <br>
+    /// The variables are as follows:
+    /// `ModifiedKeyboardInput(key, modifiers, key_state)`
+    ModifiedKeyboardInput(Key, Modifiers, KeyState),
+    /// This can be used to inform widgets of how much time has passed since they have
+    /// last been updated.
+    TimeDelta(Duration),
+    /// This is used to inform widgets that the terminal has been resized so that they can
+    /// re-calculate their dimensions or any other cached data reliant on the terminal's size.
+    TerminalResized,
+    #[default]
+    /// This is used when there is no information to report to widgets that need to be updated.
+    NoInfo,
+}
+
+impl UpdateInfo {
+    /// Get the mouse position relative to a given [`Rectangle`].
+    ///
+    /// This works for all mouse events. If the mouse is above or to the left of the [`Rectangle`],
+    /// [`UpdateInfo::NoInfo`] is returned instead.
+    ///
+    /// ```
+    /// use tuit::terminal::{MouseButton, Rectangle, UpdateInfo};
+    ///
+    /// let rect = Rectangle::new((2, 3), (10, 10));
+    ///
+    /// assert_eq!(UpdateInfo::MouseMoved(5, 5).mouse_relative_to(rect), UpdateInfo::MouseMoved(3, 2));
+    /// assert_eq!(UpdateInfo::MouseReleased(1, 5, MouseButton::Primary).mouse_relative_to(rect), UpdateInfo::NoInfo);
+    /// ```
+    #[must_use]
+    pub const fn mouse_relative_to(self, rect: Rectangle) -> Self {
+        let Some((x, y)) = self.mouse_position() else {
+            return self
+        };
+
+        let Some(x) = x.checked_sub(rect.left()) else {
+            return Self::NoInfo
+        };
+
+        let Some(y) = y.checked_sub(rect.top()) else {
+            return Self::NoInfo
+        };
+
+        self.with_mouse_position((x, y))
+    }
+
+    /// Returns the position of the mouse for mouse events.
+    #[must_use]
+    pub const fn mouse_position(self) -> Option<(usize, usize)> {
+        match self {
+            Self::CellClicked(x, y, _)
+            | Self::ModifiedCellClicked(x, y, _, _)
+            | Self::MouseReleased(x, y, _)
+            | Self::MouseDragged(x, y, _)
+            | Self::MouseMoved(x, y)
+            | Self::MouseScrolled(x, y, _) => Some((x, y)),
+            _ => None,
+        }
+    }
+
+    /// Moves the mouse of a mouse event to a different position. Other events are returned unchanged.
+    #[must_use]
+    pub const fn with_mouse_position(self, (x, y): (usize, usize)) -> Self {
+        match self {
+            Self::CellClicked(_, _, button) => Self::CellClicked(x, y, button),
+            Self::ModifiedCellClicked(_, _, button, modifiers) => Self::ModifiedCellClicked(x, y, button, modifiers),
+            Self::MouseReleased(_, _, button) => Self::MouseReleased(x, y, button),
+            Self::MouseDragged(_, _, button) => Self::MouseDragged(x, y, button),
+            Self::MouseMoved(_, _) => Self::MouseMoved(x, y),
+            Self::MouseScrolled(_, _, direction) => Self::MouseScrolled(x, y, direction),
+            _ => self,
+        }
+    }
+
+    /// Returns the [`Modifiers`] that were held down during the event. Events that can't carry
+    /// modifiers always return [`Modifiers::NONE`].
+    ///
+    /// ```
+    /// use tuit::terminal::{Key, KeyState, Modifiers, UpdateInfo};
+    ///
+    /// let copy = UpdateInfo::ModifiedKeyboardInput(Key::C, Modifiers::CTRL, KeyState::KeyDown);
+    ///
+    /// assert_eq!(copy.modifiers(), Modifiers::CTRL);
+    /// assert!(copy.ctrl());
+    /// assert_eq!(UpdateInfo::KeyboardInput(Key::C, KeyState::KeyDown).modifiers(), Modifiers::NONE);
+    /// ```
+    #[must_use]
+    pub const fn modifiers(self) -> Modifiers {
+        match self {
+            Self::ModifiedCellClicked(.., modifiers)
+            | Self::ModifiedKeyboardCharacter(_, modifiers, _)
+            | Self::ModifiedKeyboardInput(_, modifiers, _) => modifiers,
+            _ => Modifiers::NONE,
+        }
+    }
+
+    /// Returns whether Shift was held down during the event.
+    #[must_use]
+    pub const fn shift(self) -> bool {
+        self.modifiers().shift()
+    }
+
+    /// Returns whether Ctrl was held down during the event.
+    #[must_use]
+    pub const fn ctrl(self) -> bool {
+        self.modifiers().ctrl()
+    }
+
+    /// Returns whether Alt was held down during the event.
+    #[must_use]
+    pub const fn alt(self) -> bool {
+        self.modifiers().alt()
+    }
+
+    /// Returns whether Super was held down during the event.
+    #[must_use]
+    pub const fn super_key(self) -> bool {
+        self.modifiers().super_key()
+    }
+
+    /// Returns whether the event is a keyboard event.
+    #[must_use]
+    pub const fn is_keyboard(self) -> bool {
+        self.key_state().is_some()
+    }
+
+    /// Returns the [`Key`] of a [`UpdateInfo::KeyboardInput`] or [`UpdateInfo::ModifiedKeyboardInput`].
+    #[must_use]
+    pub const fn key(self) -> Option<Key> {
+        match self {
+            Self::KeyboardInput(key, _) | Self::ModifiedKeyboardInput(key, _, _) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Returns the character of a [`UpdateInfo::KeyboardCharacter`] or [`UpdateInfo::ModifiedKeyboardCharacter`].
+    #[must_use]
+    pub const fn character(self) -> Option<char> {
+        match self {
+            Self::KeyboardCharacter(character, _) | Self::ModifiedKeyboardCharacter(character, _, _) => Some(character),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`KeyState`] of a keyboard event.
+    #[must_use]
+    pub const fn key_state(self) -> Option<KeyState> {
+        match self {
+            Self::KeyboardCharacter(_, state)
+            | Self::ModifiedKeyboardCharacter(_, _, state)
+            | Self::KeyboardInput(_, state)
+            | Self::ModifiedKeyboardInput(_, _, state) => Some(state),
+            _ => None,
+        }
+    }
+
+    /// Adds `modifiers` to the event, turning it into its "modified" variant if it isn't one already.
+    /// Events that can't carry modifiers are returned unchanged.
+    ///
+    /// ```
+    /// use tuit::terminal::{KeyState, Modifiers, UpdateInfo};
+    ///
+    /// let event = UpdateInfo::KeyboardCharacter('f', KeyState::KeyDown).with_modifiers(Modifiers::ALT);
+    ///
+    /// assert_eq!(event, UpdateInfo::ModifiedKeyboardCharacter('f', Modifiers::ALT, KeyState::KeyDown));
+    /// ```
+    #[must_use]
+    pub const fn with_modifiers(self, modifiers: Modifiers) -> Self {
+        let modifiers = self.modifiers().union(modifiers);
+
+        if modifiers.is_empty() {
+            return self;
+        }
+
+        match self {
+            Self::CellClicked(x, y, button) | Self::ModifiedCellClicked(x, y, button, _) => {
+                Self::ModifiedCellClicked(x, y, button, modifiers)
+            }
+            Self::KeyboardCharacter(character, state) | Self::ModifiedKeyboardCharacter(character, _, state) => {
+                Self::ModifiedKeyboardCharacter(character, modifiers, state)
+            }
+            Self::KeyboardInput(key, state) | Self::ModifiedKeyboardInput(key, _, state) => {
+                Self::ModifiedKeyboardInput(key, modifiers, state)
+            }
+            _ => self,
+        }
+    }
+
+    /// Strips the modifiers from the event, turning a "modified" variant back into its plain variant.
+    ///
+    /// This is handy for widgets that don't care about modifiers, so that they only need to match on
+    /// the plain variants.
+    ///
+    /// ```
+    /// use tuit::terminal::{MouseButton, Modifiers, UpdateInfo};
+    ///
+    /// let click = UpdateInfo::ModifiedCellClicked(1, 2, MouseButton::Primary, Modifiers::CTRL);
+    ///
+    /// assert_eq!(click.without_modifiers(), UpdateInfo::CellClicked(1, 2, MouseButton::Primary));
+    /// ```
+    #[must_use]
+    pub const fn without_modifiers(self) -> Self {
+        match self {
+            Self::ModifiedCellClicked(x, y, button, _) => Self::CellClicked(x, y, button),
+            Self::ModifiedKeyboardCharacter(character, _, state) => Self::KeyboardCharacter(character, state),
+            Self::ModifiedKeyboardInput(key, _, state) => Self::KeyboardInput(key, state),
+            _ => self,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
+/// `UpdateResult` includes information about the object's status. Objects are unable to destruct themselves,
+/// and thus are reliant on the implementor to get rid of them once their lifecycle is over.
+/// 
+/// In the case that you are handling multiple widgets under the same [`Widget::update`](crate::widgets::Widget::update) method,
+/// you can use ordering to determine which [`UpdateResult`] to return.
+pub enum UpdateResult {
+    /// No event has occurred, the object will continue to live.
+    NoEvent = 0,
+    /// The widget has been interacted with during the last update.
+    /// (i.e. it has been clicked on, or a keystroke affected it).
+    Interacted = 1,
+    /// The object's lifecycle has ended, and it should now be destructured.
+    LifecycleEnd = 2,
+}
@@ -0,0 +1,727 @@
+use crate::terminal::{Key, KeyState, Modifiers, UpdateInfo};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// The scancode sets that a PS/2 keyboard can send.
+pub enum ScancodeSet {
+    /// Scancode set 1, also known as the "XT" set. Most PS/2 controllers translate the keyboard's
+    /// output to this set by default.
+    Set1,
+    /// Scancode set 2, also known as the "AT" set. This is what PS/2 keyboards send natively.
+    Set2,
+}
+
+/// A table that maps keys to the characters they type, used by [`ScancodeTranslator`].
+///
+/// Both tables are indexed by the key's USB HID usage ID. Keys that don't type a character, like Enter or
+/// the arrow keys, are `None`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct KeyboardLayout {
+    /// The characters typed by each key when Shift is not held.
+    pub unshifted: [Option<char>; Self::KEYS],
+    /// The characters typed by each key when Shift is held.
+    pub shifted: [Option<char>; Self::KEYS],
+}
+
+impl KeyboardLayout {
+    /// The number of keys in each of a [`KeyboardLayout`]'s tables. This covers every HID usage ID up to and
+    /// including the keypad.
+    pub const KEYS: usize = 0x68;
+
+    /// The US QWERTY layout.
+    pub const US: Self = Self {
+        unshifted: ascii_table(&[
+            (0x04, b"abcdefghijklmnopqrstuvwxyz1234567890"),
+            (0x2C, b" -=[]\\"),
+            (0x33, b";'`,./"),
+            (0x54, b"/*-+"),
+            (0x59, b"1234567890."),
+            (0x64, b"\\"),
+        ]),
+        shifted: ascii_table(&[
+            (0x04, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ!@#$%^&*()"),
+            (0x2C, b" _+{}|"),
+            (0x33, b":\"~<>?"),
+            (0x54, b"/*-+"),
+            (0x59, b"1234567890."),
+            (0x64, b"|"),
+        ]),
+    };
+
+    /// Returns the character typed by the [`Key`], if it types one.
+    #[must_use]
+    pub const fn character(&self, key: Key, shifted: bool) -> Option<char> {
+        let table = if shifted { &self.shifted } else { &self.unshifted };
+        let code = key.code() as usize;
+
+        if code < Self::KEYS {
+            table[code]
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::US
+    }
+}
+
+/// Builds a [`KeyboardLayout`] table from runs of ASCII characters, each starting at a HID usage ID.
+const fn ascii_table(runs: &[(u8, &[u8])]) -> [Option<char>; KeyboardLayout::KEYS] {
+    let mut table = [None; KeyboardLayout::KEYS];
+    let mut run = 0;
+
+    while run < runs.len() {
+        let (start, characters) = runs[run];
+        let mut idx = 0;
+
+        while idx < characters.len() {
+            table[start as usize + idx] = Some(characters[idx] as char);
+            idx += 1;
+        }
+
+        run += 1;
+    }
+
+    table
+}
+
+/// A stateful translator that turns the scancodes sent by a PS/2 keyboard into [`UpdateInfo`]s.
+///
+/// Scancodes are fed to the translator one byte at a time using [`ScancodeTranslator::feed`]. The
+/// translator keeps track of extended (`0xE0`) prefixes, break codes, which keys are held down, and the
+/// state of the modifier keys and Caps Lock.
+///
+/// - Keys that type a character according to the [`KeyboardLayout`] are reported as
+///   [`UpdateInfo::KeyboardCharacter`], unless Ctrl is held. If Alt or Super are held, they're reported
+///   as [`UpdateInfo::ModifiedKeyboardCharacter`] instead.
+/// - Every other key is reported as [`UpdateInfo::KeyboardInput`], or as
+///   [`UpdateInfo::ModifiedKeyboardInput`] if any modifier keys are held.
+/// - Keys are reported as [`KeyState::KeyDown`] when they're pressed, [`KeyState::KeyHeld`] when the
+///   keyboard repeats them, and [`KeyState::KeyUp`] when they're released.
+///
+/// The keypad always types its characters, because the translator can't know whether Num Lock is on.
+///
+/// ## Scancode set 1
+///
+/// ```
+/// use tuit::terminal::{Key, KeyState, ScancodeSet, ScancodeTranslator, UpdateInfo};
+///
+/// let mut translator = ScancodeTranslator::new(ScancodeSet::Set1);
+/// // Left Shift, then A, then release both.
+/// let scancodes = [0x2A, 0x1E, 0x9E, 0xAA];
+///
+/// let mut events = scancodes.into_iter().filter_map(|byte| translator.feed(byte));
+///
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardInput(Key::LeftShift, KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardCharacter('A', KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardCharacter('A', KeyState::KeyUp)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardInput(Key::LeftShift, KeyState::KeyUp)));
+/// assert_eq!(events.next(), None);
+/// ```
+///
+/// ## Scancode set 2
+///
+/// ```
+/// use tuit::terminal::{Key, KeyState, ScancodeSet, ScancodeTranslator, UpdateInfo};
+///
+/// let mut translator = ScancodeTranslator::new(ScancodeSet::Set2);
+/// // The up arrow, then A being held down until the keyboard repeats it.
+/// let scancodes = [0xE0, 0x75, 0xE0, 0xF0, 0x75, 0x1C, 0x1C, 0xF0, 0x1C];
+///
+/// let mut events = scancodes.into_iter().filter_map(|byte| translator.feed(byte));
+///
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardInput(Key::Up, KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardInput(Key::Up, KeyState::KeyUp)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardCharacter('a', KeyState::KeyDown)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardCharacter('a', KeyState::KeyHeld)));
+/// assert_eq!(events.next(), Some(UpdateInfo::KeyboardCharacter('a', KeyState::KeyUp)));
+/// assert_eq!(events.next(), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ScancodeTranslator<'a> {
+    set: ScancodeSet,
+    layout: &'a KeyboardLayout,
+    /// Whether the previous byte was an extended (`0xE0`) prefix.
+    extended: bool,
+    /// Whether the previous byte was a break (`0xF0`) prefix. Only used by scancode set 2.
+    released: bool,
+    /// The number of bytes left in the current Pause key (`0xE1`) sequence.
+    pause_remaining: u8,
+    /// A bitset of the keys that are held down, indexed by HID usage ID.
+    pressed: [u32; 8],
+    caps_lock: bool,
+}
+
+impl ScancodeTranslator<'static> {
+    /// Create a new [`ScancodeTranslator`] for the given [`ScancodeSet`], using the US layout.
+    #[must_use]
+    pub const fn new(set: ScancodeSet) -> Self {
+        Self {
+            set,
+            layout: &KeyboardLayout::US,
+            extended: false,
+            released: false,
+            pause_remaining: 0,
+            pressed: [0; 8],
+            caps_lock: false,
+        }
+    }
+}
+
+impl<'a> ScancodeTranslator<'a> {
+    /// Use a different [`KeyboardLayout`] to work out which characters the keys type.
+    ///
+    /// ```
+    /// use tuit::terminal::{Key, KeyboardLayout, KeyState, ScancodeSet, ScancodeTranslator, UpdateInfo};
+    ///
+    /// // Swap Y and Z, like on a German keyboard.
+    /// let mut layout = KeyboardLayout::US;
+    /// let (y, z) = (usize::from(u8::from(Key::Y)), usize::from(u8::from(Key::Z)));
+    /// layout.unshifted.swap(y, z);
+    /// layout.shifted.swap(y, z);
+    ///
+    /// let mut translator = ScancodeTranslator::new(ScancodeSet::Set1).with_layout(&layout);
+    ///
+    /// assert_eq!(translator.feed(0x15), Some(UpdateInfo::KeyboardCharacter('z', KeyState::KeyDown)));
+    /// ```
+    #[must_use]
+    pub const fn with_layout(self, layout: &KeyboardLayout) -> ScancodeTranslator<'_> {
+        ScancodeTranslator {
+            set: self.set,
+            layout,
+            extended: self.extended,
+            released: self.released,
+            pause_remaining: self.pause_remaining,
+            pressed: self.pressed,
+            caps_lock: self.caps_lock,
+        }
+    }
+
+    /// Returns the [`ScancodeSet`] that the translator expects.
+    #[must_use]
+    pub const fn set(&self) -> ScancodeSet {
+        self.set
+    }
+
+    /// Returns the [`KeyboardLayout`] that the translator uses.
+    #[must_use]
+    pub const fn layout(&self) -> &'a KeyboardLayout {
+        self.layout
+    }
+
+    /// Returns whether the [`Key`] is held down.
+    #[must_use]
+    pub const fn is_pressed(&self, key: Key) -> bool {
+        let code = key.code();
+
+        self.pressed[code as usize / 32] & (1 << (code % 32)) != 0
+    }
+
+    /// Returns the [`Modifiers`] of the modifier keys that are held down.
+    #[must_use]
+    pub const fn modifiers(&self) -> Modifiers {
+        const MODIFIER_KEYS: [Key; 8] = [
+            Key::LeftCtrl,
+            Key::LeftShift,
+            Key::LeftAlt,
+            Key::LeftSuper,
+            Key::RightCtrl,
+            Key::RightShift,
+            Key::RightAlt,
+            Key::RightSuper,
+        ];
+
+        let mut modifiers = Modifiers::NONE;
+        let mut idx = 0;
+
+        while idx < MODIFIER_KEYS.len() {
+            if self.is_pressed(MODIFIER_KEYS[idx]) {
+                modifiers = modifiers.union(MODIFIER_KEYS[idx].modifier());
+            }
+
+            idx += 1;
+        }
+
+        modifiers
+    }
+
+    /// Returns whether Caps Lock is on.
+    #[must_use]
+    pub const fn caps_lock(&self) -> bool {
+        self.caps_lock
+    }
+
+    /// Feed the next byte received from the keyboard into the translator.
+    ///
+    /// Returns `None` if the byte doesn't complete a scancode, or if it is a response to a command
+    /// (like `0xFA` for "acknowledged") rather than a scancode.
+    pub fn feed(&mut self, byte: u8) -> Option<UpdateInfo> {
+        if self.pause_remaining > 0 {
+            return self.pause(byte);
+        }
+
+        match (self.set, byte) {
+            (_, 0x00 | 0xFA | 0xFE | 0xFF) | (ScancodeSet::Set2, 0xAA | 0xEE) => return None,
+            (_, 0xE0) => {
+                self.extended = true;
+
+                return None;
+            }
+            (_, 0xE1) => {
+                self.pause_remaining = 2;
+
+                return None;
+            }
+            (ScancodeSet::Set2, 0xF0) => {
+                self.released = true;
+
+                return None;
+            }
+            _ => {}
+        }
+
+        let extended = core::mem::take(&mut self.extended);
+
+        let (key, released) = match self.set {
+            ScancodeSet::Set1 if extended => (set1_extended_to_hid(byte & 0x7F), byte & 0x80 != 0),
+            ScancodeSet::Set1 => (set1_to_hid(byte & 0x7F), byte & 0x80 != 0),
+            ScancodeSet::Set2 if extended => (set2_extended_to_hid(byte), core::mem::take(&mut self.released)),
+            ScancodeSet::Set2 => (set2_to_hid(byte), core::mem::take(&mut self.released)),
+        };
+
+        // Some keys are surrounded by "fake" Shift presses, which have no HID usage ID and get dropped here.
+        Some(self.key_event(Key::from_code(key?), released))
+    }
+
+    /// The Pause key sends a sequence starting with `0xE1` when it is pressed, immediately followed by
+    /// another one for its release. Neither contains any information we need, so we just count bytes.
+    fn pause(&mut self, byte: u8) -> Option<UpdateInfo> {
+        match (self.set, byte) {
+            (ScancodeSet::Set2, 0xF0) => {
+                self.released = true;
+
+                return None;
+            }
+            (ScancodeSet::Set1, _) if byte & 0x80 != 0 => self.released = true,
+            _ => {}
+        }
+
+        self.pause_remaining -= 1;
+
+        if self.pause_remaining > 0 {
+            return None;
+        }
+
+        let released = core::mem::take(&mut self.released);
+
+        Some(self.key_event(Key::Pause, released))
+    }
+
+    fn key_event(&mut self, key: Key, released: bool) -> UpdateInfo {
+        let code = key.code();
+        let (word, bit) = (code as usize / 32, 1 << (code % 32));
+
+        let state = if released {
+            self.pressed[word] &= !bit;
+
+            KeyState::KeyUp
+        } else if self.is_pressed(key) {
+            KeyState::KeyHeld
+        } else {
+            self.pressed[word] |= bit;
+
+            KeyState::KeyDown
+        };
+
+        if key == Key::CapsLock && state == KeyState::KeyDown {
+            self.caps_lock = !self.caps_lock;
+        }
+
+        // Modifier keys are reported by themselves.
+        let modifiers = if key.is_modifier() {
+            Modifiers::NONE
+        } else {
+            self.modifiers()
+        };
+
+        if modifiers.ctrl() {
+            return UpdateInfo::ModifiedKeyboardInput(key, modifiers, state);
+        }
+
+        // Caps Lock only affects letters.
+        let caps = self.caps_lock
+            && self
+                .layout
+                .character(key, false)
+                .is_some_and(char::is_alphabetic);
+
+        if let Some(character) = self.layout.character(key, modifiers.shift() != caps) {
+            // Shift is already accounted for by the character.
+            return UpdateInfo::KeyboardCharacter(character, state)
+                .with_modifiers(modifiers.difference(Modifiers::SHIFT));
+        }
+
+        UpdateInfo::KeyboardInput(key, state).with_modifiers(modifiers)
+    }
+}
+
+/// Returns the HID usage ID of a scancode set 1 make code that follows an extended (`0xE0`) prefix.
+const fn set1_extended_to_hid(code: u8) -> Option<u8> {
+    let key = match code {
+        0x1C => 0x58, // Keypad Enter
+        0x1D => 0xE4, // Right Ctrl
+        0x35 => 0x54, // Keypad /
+        0x37 => 0x46, // Print Screen
+        0x38 => 0xE6, // Right Alt
+        0x47 => 0x4A, // Home
+        0x48 => 0x52, // Up
+        0x49 => 0x4B, // Page Up
+        0x4B => 0x50, // Left
+        0x4D => 0x4F, // Right
+        0x4F => 0x4D, // End
+        0x50 => 0x51, // Down
+        0x51 => 0x4E, // Page Down
+        0x52 => 0x49, // Insert
+        0x53 => 0x4C, // Delete
+        0x5B => 0xE3, // Left GUI
+        0x5C => 0xE7, // Right GUI
+        0x5D => 0x65, // Menu
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Returns the HID usage ID of a scancode set 1 make code.
+const fn set1_to_hid(code: u8) -> Option<u8> {
+    let key = match code {
+        0x01 => 0x29, // Escape
+        0x02..=0x0A => 0x1E + (code - 0x02), // 1 through 9
+        0x0B => 0x27, // 0
+        0x0C => 0x2D, // -
+        0x0D => 0x2E, // =
+        0x0E => 0x2A, // Backspace
+        0x0F => 0x2B, // Tab
+        0x10 => 0x14, // Q
+        0x11 => 0x1A, // W
+        0x12 => 0x08, // E
+        0x13 => 0x15, // R
+        0x14 => 0x17, // T
+        0x15 => 0x1C, // Y
+        0x16 => 0x18, // U
+        0x17 => 0x0C, // I
+        0x18 => 0x12, // O
+        0x19 => 0x13, // P
+        0x1A => 0x2F, // [
+        0x1B => 0x30, // ]
+        0x1C => 0x28, // Enter
+        0x1D => 0xE0, // Left Ctrl
+        0x1E => 0x04, // A
+        0x1F => 0x16, // S
+        0x20 => 0x07, // D
+        0x21 => 0x09, // F
+        0x22 => 0x0A, // G
+        0x23 => 0x0B, // H
+        0x24 => 0x0D, // J
+        0x25 => 0x0E, // K
+        0x26 => 0x0F, // L
+        0x27 => 0x33, // ;
+        0x28 => 0x34, // '
+        0x29 => 0x35, // `
+        0x2A => 0xE1, // Left Shift
+        0x2B => 0x31, // \
+        0x2C => 0x1D, // Z
+        0x2D => 0x1B, // X
+        0x2E => 0x06, // C
+        0x2F => 0x19, // V
+        0x30 => 0x05, // B
+        0x31 => 0x11, // N
+        0x32 => 0x10, // M
+        0x33 => 0x36, // ,
+        0x34 => 0x37, // .
+        0x35 => 0x38, // /
+        0x36 => 0xE5, // Right Shift
+        0x37 => 0x55, // Keypad *
+        0x38 => 0xE2, // Left Alt
+        0x39 => 0x2C, // Space
+        0x3A => 0x39, // Caps Lock
+        0x3B..=0x44 => 0x3A + (code - 0x3B), // F1 through F10
+        0x45 => 0x53, // Num Lock
+        0x46 => 0x47, // Scroll Lock
+        0x47 => 0x5F, // Keypad 7
+        0x48 => 0x60, // Keypad 8
+        0x49 => 0x61, // Keypad 9
+        0x4A => 0x56, // Keypad -
+        0x4B => 0x5C, // Keypad 4
+        0x4C => 0x5D, // Keypad 5
+        0x4D => 0x5E, // Keypad 6
+        0x4E => 0x57, // Keypad +
+        0x4F => 0x59, // Keypad 1
+        0x50 => 0x5A, // Keypad 2
+        0x51 => 0x5B, // Keypad 3
+        0x52 => 0x62, // Keypad 0
+        0x53 => 0x63, // Keypad .
+        0x54 => 0x46, // Alt + Print Screen
+        0x56 => 0x64, // Non-US \
+        0x57 => 0x44, // F11
+        0x58 => 0x45, // F12
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Returns the HID usage ID of a scancode set 2 make code that follows an extended (`0xE0`) prefix.
+const fn set2_extended_to_hid(code: u8) -> Option<u8> {
+    let key = match code {
+        0x11 => 0xE6, // Right Alt
+        0x14 => 0xE4, // Right Ctrl
+        0x1F => 0xE3, // Left GUI
+        0x27 => 0xE7, // Right GUI
+        0x2F => 0x65, // Menu
+        0x4A => 0x54, // Keypad /
+        0x5A => 0x58, // Keypad Enter
+        0x69 => 0x4D, // End
+        0x6B => 0x50, // Left
+        0x6C => 0x4A, // Home
+        0x70 => 0x49, // Insert
+        0x71 => 0x4C, // Delete
+        0x72 => 0x51, // Down
+        0x74 => 0x4F, // Right
+        0x75 => 0x52, // Up
+        0x7A => 0x4E, // Page Down
+        0x7C => 0x46, // Print Screen
+        0x7D => 0x4B, // Page Up
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+/// Returns the HID usage ID of a scancode set 2 make code.
+const fn set2_to_hid(code: u8) -> Option<u8> {
+    let key = match code {
+        0x01 => 0x42, // F9
+        0x03 => 0x3E, // F5
+        0x04 => 0x3C, // F3
+        0x05 => 0x3A, // F1
+        0x06 => 0x3B, // F2
+        0x07 => 0x45, // F12
+        0x09 => 0x43, // F10
+        0x0A => 0x41, // F8
+        0x0B => 0x3F, // F6
+        0x0C => 0x3D, // F4
+        0x0D => 0x2B, // Tab
+        0x0E => 0x35, // `
+        0x11 => 0xE2, // Left Alt
+        0x12 => 0xE1, // Left Shift
+        0x14 => 0xE0, // Left Ctrl
+        0x15 => 0x14, // Q
+        0x16 => 0x1E, // 1
+        0x1A => 0x1D, // Z
+        0x1B => 0x16, // S
+        0x1C => 0x04, // A
+        0x1D => 0x1A, // W
+        0x1E => 0x1F, // 2
+        0x21 => 0x06, // C
+        0x22 => 0x1B, // X
+        0x23 => 0x07, // D
+        0x24 => 0x08, // E
+        0x25 => 0x21, // 4
+        0x26 => 0x20, // 3
+        0x29 => 0x2C, // Space
+        0x2A => 0x19, // V
+        0x2B => 0x09, // F
+        0x2C => 0x17, // T
+        0x2D => 0x15, // R
+        0x2E => 0x22, // 5
+        0x31 => 0x11, // N
+        0x32 => 0x05, // B
+        0x33 => 0x0B, // H
+        0x34 => 0x0A, // G
+        0x35 => 0x1C, // Y
+        0x36 => 0x23, // 6
+        0x3A => 0x10, // M
+        0x3B => 0x0D, // J
+        0x3C => 0x18, // U
+        0x3D => 0x24, // 7
+        0x3E => 0x25, // 8
+        0x41 => 0x36, // ,
+        0x42 => 0x0E, // K
+        0x43 => 0x0C, // I
+        0x44 => 0x12, // O
+        0x45 => 0x27, // 0
+        0x46 => 0x26, // 9
+        0x49 => 0x37, // .
+        0x4A => 0x38, // /
+        0x4B => 0x0F, // L
+        0x4C => 0x33, // ;
+        0x4D => 0x13, // P
+        0x4E => 0x2D, // -
+        0x52 => 0x34, // '
+        0x54 => 0x2F, // [
+        0x55 => 0x2E, // =
+        0x58 => 0x39, // Caps Lock
+        0x59 => 0xE5, // Right Shift
+        0x5A => 0x28, // Enter
+        0x5B => 0x30, // ]
+        0x5D => 0x31, // \
+        0x61 => 0x64, // Non-US \
+        0x66 => 0x2A, // Backspace
+        0x69 => 0x59, // Keypad 1
+        0x6B => 0x5C, // Keypad 4
+        0x6C => 0x5F, // Keypad 7
+        0x70 => 0x62, // Keypad 0
+        0x71 => 0x63, // Keypad .
+        0x72 => 0x5A, // Keypad 2
+        0x73 => 0x5D, // Keypad 5
+        0x74 => 0x5E, // Keypad 6
+        0x75 => 0x60, // Keypad 8
+        0x76 => 0x29, // Escape
+        0x77 => 0x53, // Num Lock
+        0x78 => 0x44, // F11
+        0x79 => 0x57, // Keypad +
+        0x7A => 0x5B, // Keypad 3
+        0x7B => 0x56, // Keypad -
+        0x7C => 0x55, // Keypad *
+        0x7D => 0x61, // Keypad 9
+        0x7E => 0x47, // Scroll Lock
+        0x83 => 0x40, // F7
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::{ScancodeSet, ScancodeTranslator};
+    use crate::terminal::{Key, KeyState, Modifiers, UpdateInfo};
+
+    /// Feeds every byte into a fresh translator and collects the events that come out.
+    fn translate(set: ScancodeSet, bytes: &[u8]) -> Vec<UpdateInfo> {
+        let mut translator = ScancodeTranslator::new(set);
+
+        bytes.iter().filter_map(|&byte| translator.feed(byte)).collect()
+    }
+
+    #[test]
+    fn set1_caps_lock() {
+        assert_eq!(
+            translate(ScancodeSet::Set1, &[0x3A, 0xBA, 0x1E, 0x9E, 0x2A, 0x1E, 0x9E, 0xAA, 0x02, 0x82]),
+            [
+                UpdateInfo::KeyboardInput(Key::CapsLock, KeyState::KeyDown),
+                UpdateInfo::KeyboardInput(Key::CapsLock, KeyState::KeyUp),
+                UpdateInfo::KeyboardCharacter('A', KeyState::KeyDown),
+                UpdateInfo::KeyboardCharacter('A', KeyState::KeyUp),
+                UpdateInfo::KeyboardInput(Key::LeftShift, KeyState::KeyDown),
+                // Shift undoes Caps Lock for letters...
+                UpdateInfo::KeyboardCharacter('a', KeyState::KeyDown),
+                UpdateInfo::KeyboardCharacter('a', KeyState::KeyUp),
+                UpdateInfo::KeyboardInput(Key::LeftShift, KeyState::KeyUp),
+                // ...but Caps Lock doesn't affect other keys.
+                UpdateInfo::KeyboardCharacter('1', KeyState::KeyDown),
+                UpdateInfo::KeyboardCharacter('1', KeyState::KeyUp),
+            ]
+        );
+    }
+
+    #[test]
+    fn set1_extended_modifiers() {
+        // Right Ctrl, C, then release both.
+        assert_eq!(
+            translate(ScancodeSet::Set1, &[0xE0, 0x1D, 0x2E, 0xAE, 0xE0, 0x9D]),
+            [
+                UpdateInfo::KeyboardInput(Key::RightCtrl, KeyState::KeyDown),
+                UpdateInfo::ModifiedKeyboardInput(Key::C, Modifiers::CTRL, KeyState::KeyDown),
+                UpdateInfo::ModifiedKeyboardInput(Key::C, Modifiers::CTRL, KeyState::KeyUp),
+                UpdateInfo::KeyboardInput(Key::RightCtrl, KeyState::KeyUp),
+            ]
+        );
+    }
+
+    #[test]
+    fn set1_fake_shifts() {
+        // Print Screen is surrounded by a fake Left Shift press and release.
+        assert_eq!(
+            translate(ScancodeSet::Set1, &[0xE0, 0x2A, 0xE0, 0x37, 0xE0, 0xB7, 0xE0, 0xAA]),
+            [
+                UpdateInfo::KeyboardInput(Key::PrintScreen, KeyState::KeyDown),
+                UpdateInfo::KeyboardInput(Key::PrintScreen, KeyState::KeyUp),
+            ]
+        );
+    }
+
+    #[test]
+    fn set1_pause() {
+        assert_eq!(
+            translate(ScancodeSet::Set1, &[0xE1, 0x1D, 0x45, 0xE1, 0x9D, 0xC5]),
+            [
+                UpdateInfo::KeyboardInput(Key::Pause, KeyState::KeyDown),
+                UpdateInfo::KeyboardInput(Key::Pause, KeyState::KeyUp),
+            ]
+        );
+    }
+
+    #[test]
+    fn set2_pause() {
+        assert_eq!(
+            translate(ScancodeSet::Set2, &[0xE1, 0x14, 0x77, 0xE1, 0xF0, 0x14, 0xF0, 0x77]),
+            [
+                UpdateInfo::KeyboardInput(Key::Pause, KeyState::KeyDown),
+                UpdateInfo::KeyboardInput(Key::Pause, KeyState::KeyUp),
+            ]
+        );
+    }
+
+    #[test]
+    fn set2_shifted_and_alt_characters() {
+        // Shift+1, then Alt+A.
+        assert_eq!(
+            translate(ScancodeSet::Set2, &[0x12, 0x16, 0xF0, 0x16, 0xF0, 0x12, 0x11, 0x1C, 0xF0, 0x1C, 0xF0, 0x11]),
+            [
+                UpdateInfo::KeyboardInput(Key::LeftShift, KeyState::KeyDown),
+                UpdateInfo::KeyboardCharacter('!', KeyState::KeyDown),
+                UpdateInfo::KeyboardCharacter('!', KeyState::KeyUp),
+                UpdateInfo::KeyboardInput(Key::LeftShift, KeyState::KeyUp),
+                UpdateInfo::KeyboardInput(Key::LeftAlt, KeyState::KeyDown),
+                UpdateInfo::ModifiedKeyboardCharacter('a', Modifiers::ALT, KeyState::KeyDown),
+                UpdateInfo::ModifiedKeyboardCharacter('a', Modifiers::ALT, KeyState::KeyUp),
+                UpdateInfo::KeyboardInput(Key::LeftAlt, KeyState::KeyUp),
+            ]
+        );
+    }
+
+    #[test]
+    fn command_responses_are_ignored() {
+        assert_eq!(translate(ScancodeSet::Set1, &[0xFA, 0xFE, 0x00, 0xFF]), []);
+        assert_eq!(translate(ScancodeSet::Set2, &[0xAA, 0xFA, 0xEE, 0x1C]), [UpdateInfo::KeyboardCharacter('a', KeyState::KeyDown)]);
+    }
+
+    #[test]
+    fn pressed_keys_are_tracked() {
+        let mut translator = ScancodeTranslator::new(ScancodeSet::Set2);
+
+        for byte in [0x14, 0x59] {
+            translator.feed(byte);
+        }
+
+        assert!(translator.is_pressed(Key::LeftCtrl));
+        assert!(translator.is_pressed(Key::RightShift));
+        assert_eq!(translator.modifiers(), Modifiers::CTRL.union(Modifiers::SHIFT));
+
+        for byte in [0xF0, 0x14] {
+            translator.feed(byte);
+        }
+
+        assert!(!translator.is_pressed(Key::LeftCtrl));
+        assert_eq!(translator.modifiers(), Modifiers::SHIFT);
+    }
+}
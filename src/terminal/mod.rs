@@ -112,6 +112,7 @@ pub use const_size::ConstantSize;
 pub use const_size_ref::ConstantSizeRef;
 pub use interactive::*;
 pub use max_size::MaxSize;
+pub use pane::Pane;
 pub use view::View;
 pub use view_split::ViewSplit;
 
@@ -121,8 +122,9 @@ use crate::style::Style;
 use crate::terminal;
 use crate::widgets::Direction;
 
-/// Module containing all the code required for the "interactive" aspects of Tuit. This includes code
-/// like structs for handling input, like [`interactive::MouseButton`] or [`interactive::KeyState`].
+/// Module containing all the code required for the "interactive" aspects of Tuit.
+///
+/// This includes code like structs for handling input, like [`interactive::MouseButton`] or [`interactive::KeyState`].
 pub mod interactive;
 
 
@@ -137,10 +139,14 @@ pub mod max_size;
 pub mod dummy;
 /// The [`View`] terminal that can provide mutable or immutable views into terminals.
 pub mod view;
+/// The [`Pane`] terminal, which is a view into part of a terminal that can be drawn into alongside other panes.
+pub mod pane;
 /// The iterator used by the [`View`] terminal.
 pub mod view_iterator;
 /// The [`ViewSplit`] struct, which is used to split the terminal along its axes.
 pub mod view_split;
+/// Utilities for working with the display width of characters, like [`width::CellWriter`].
+pub mod width;
 /// The [`Debug`] terminal, which prints out the terminal's state every time [`TerminalConst::cell`] is called or writes
 /// an [`Ansi4::Red`](crate::style::Ansi4) to the background of modified cells.
 pub mod debug;
@@ -158,6 +164,15 @@ pub struct Cell {
 }
 
 impl Cell {
+    /// The character placed in the cell that follows a wide character (like `日` or most emoji).
+    ///
+    /// Wide characters take up two columns when they are displayed, so the cell after them gets
+    /// covered. Renderers skip over continuation cells that follow a wide character, and display
+    /// any other continuation cells as a space.
+    ///
+    /// It is a Unicode noncharacter, so it will never be assigned a glyph.
+    pub const CONTINUATION: char = '\u{FFFF}';
+
     /// Create a new cell using the default style and the specified character.
     #[must_use]
     pub const fn new(character: char) -> Self {
@@ -166,6 +181,22 @@ impl Cell {
             style: Style::new(),
         }
     }
+
+    /// Returns whether the cell is covered by a wide character in the cell before it.
+    ///
+    /// See [`Cell::CONTINUATION`].
+    #[must_use]
+    pub const fn is_continuation(&self) -> bool {
+        self.character == Self::CONTINUATION
+    }
+
+    /// Returns the number of columns that the cell's character takes up when displayed.
+    ///
+    /// See [`width::of_char`].
+    #[must_use]
+    pub fn width(&self) -> usize {
+        width::of_char(self.character)
+    }
 }
 
 /// Allows you to access properties like the dimensions of a terminal and its default style.
@@ -594,6 +625,8 @@ impl Rectangle {
 
     /// Check if the given [`Rectangle`] is within the bounds of this [`Rectangle`].
     ///
+    /// Empty rectangles are within the bounds as long as they don't go past the edges.
+    ///
     /// ```
     /// # use std::ops::Not;
     /// use tuit::terminal::Rectangle;
@@ -601,15 +634,15 @@ impl Rectangle {
     /// let rectangle = Rectangle::of_size((20, 20));
     /// let other_rectangle = Rectangle::new((1,2), (21, 21));
     ///
-    /// assert!(rectangle.contains_rect(other_rectangle).not())
+    /// assert!(rectangle.contains_rect(other_rectangle).not());
+    /// assert!(rectangle.contains_rect(Rectangle::new((20, 0), (20, 20))));
     /// ```
     #[must_use]
     pub const fn contains_rect(&self, rect: Self) -> bool {
-        let (left_top, right_bottom) = (rect.left_top(), rect.right_bottom());
+        let x_in_bounds = rect.left() >= self.left() && rect.right() <= self.right();
+        let y_in_bounds = rect.top() >= self.top() && rect.bottom() <= self.bottom();
 
-        let right_bottom = (right_bottom.0 - 1, right_bottom.1 - 1);
-        
-        self.contains(left_top) && self.contains(right_bottom)
+        x_in_bounds && y_in_bounds
     }
 
     /// Moves the [`Rectangle`] to the specified position, centered around the top-left vertex.
@@ -643,7 +676,7 @@ impl Rectangle {
         let (left, right) = (self.left(), self.right());
 
         // Average of left/right
-        (left + right) / 2
+        usize::midpoint(left, right)
     }
 
     /// Get the center of the rectangle on the y-axis.
@@ -652,7 +685,7 @@ impl Rectangle {
         let (top, bottom) = (self.top(), self.bottom());
 
         // Average of top/bottom
-        (top + bottom) / 2
+        usize::midpoint(top, bottom)
     }
 
     /// Get the center of the rectangle.
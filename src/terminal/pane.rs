@@ -0,0 +1,75 @@
+use crate::style::Style;
+use crate::terminal::{Cell, Metadata, TerminalConst, TerminalMut};
+
+#[allow(unused_imports)] // used in docs.
+use crate::terminal::{View, ViewSplit};
+
+/// A mutable view into part of a terminal that owns references to its own cells.
+///
+/// Unlike a [`View`], a [`Pane`] doesn't borrow its parent terminal, so several [`Pane`]s into
+/// different parts of the same terminal can be drawn into at the same time. They're created by
+/// [`ViewSplit::panes_mut`], which hands out the references to the parent's cells.
+///
+/// The references are stored in a caller-provided slice, so creating a [`Pane`] never allocates.
+#[derive(Debug)]
+pub struct Pane<'a, 'b> {
+    /// The cells inside the pane, row by row.
+    cells: &'b mut [Option<&'a mut Cell>],
+    /// The width and height of the pane.
+    dimensions: (usize, usize),
+    /// The default style of the parent terminal.
+    default_style: Style,
+}
+
+impl<'a, 'b> Pane<'a, 'b> {
+    /// Create a new [`Pane`] out of the given cells, row by row.
+    pub(crate) const fn new(cells: &'b mut [Option<&'a mut Cell>], dimensions: (usize, usize), default_style: Style) -> Self {
+        Self {
+            cells,
+            dimensions,
+            default_style,
+        }
+    }
+}
+
+impl Metadata for Pane<'_, '_> {
+    fn dimensions(&self) -> (usize, usize) {
+        self.dimensions
+    }
+
+    fn default_style(&self) -> Style {
+        self.default_style
+    }
+}
+
+impl TerminalConst for Pane<'_, '_> {
+    fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.cells.iter().flatten().map(|cell| &**cell)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        let (width, height) = self.dimensions;
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        self.cells.get(x + width * y)?.as_deref()
+    }
+}
+
+impl TerminalMut for Pane<'_, '_> {
+    fn cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        self.cells.iter_mut().flatten().map(|cell| &mut **cell)
+    }
+
+    fn cell_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        let (width, height) = self.dimensions;
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        self.cells.get_mut(x + width * y)?.as_deref_mut()
+    }
+}
@@ -16,6 +16,10 @@ impl<I: Iterator> Iterator for ViewIterator<I> {
         let (width, height) = self.view_rect.dimensions();
         let (x, y) = &mut self.current_coord;
 
+        if width == 0 || height == 0 {
+            return None;
+        }
+
         *x += 1;
 
         if *x > width {
@@ -1,12 +1,41 @@
 use crate::style::Style;
-use crate::terminal::{Cell, Metadata, Rectangle, Rescalable, TerminalConst, TerminalMut};
+use crate::terminal::{Cell, Metadata, Pane, Rectangle, Rescalable, TerminalConst, TerminalMut};
 use crate::terminal::view::View;
-use crate::widgets::Direction;
+use crate::widgets::{Axis, Direction};
 
 #[allow(unused_imports)] // used in docs.
 use crate::terminal::Terminal;
 
 /// A view splitter -- can split views both horizontally and vertically.
+///
+/// Besides halving the terminal, it can split it at any offset or ratio, or into any number of
+/// weighted pieces at once. The `_mut` splits hand out [`Pane`]s instead of [`View`]s, which can
+/// all be drawn into at the same time because each of them only holds references to its own cells.
+///
+/// Splitting at the very start or end of the terminal is allowed, and gives an empty piece that has
+/// no cells to draw into. Because every piece stays inside of the terminal, even when it's empty,
+/// the splits that return [`View`]s never fail to create them.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{Cell, ConstantSize, ViewSplit};
+/// use tuit::widgets::Axis;
+/// use tuit::widgets::builtins::Text;
+///
+/// let mut terminal: ConstantSize<20, 2> = ConstantSize::new();
+/// let mut split = ViewSplit::new(&mut terminal);
+///
+/// // Needs to be able to hold a reference to every cell of the terminal.
+/// let mut buffer = [const { None::<&mut Cell> }; 20 * 2];
+/// let [mut left, mut right] = split.split_ratio_mut(Axis::Horizontal, 0.25, &mut buffer).expect("The buffer is large enough");
+///
+/// // Both panes are borrowed at the same time.
+/// Text::new("Logs").drawn(&mut left).expect("Should draw successfully");
+/// Text::new("Everything is fine").drawn(&mut right).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, 'L');
+/// assert_eq!(terminal.cell(5, 0).unwrap().character, 'E');
+/// ```
 pub struct ViewSplit<T> {
     child: T
 }
@@ -131,6 +160,148 @@ impl<T> ViewSplit<T> {
         self.child.view_mut(bounding_box).expect("View should've been valid.")
     }
 
+    /// Returns the two [`Rectangle`]s on either side of a split `offset` cells along `axis`. The
+    /// offset is clamped to the size of the terminal.
+    pub fn rects_at(&self, axis: Axis, offset: usize) -> [Rectangle; 2]
+    where T: Metadata {
+        let bounds = self.child.bounding_box();
+
+        pieces(bounds, axis, [offset, length_along(bounds, axis)])
+    }
+
+    /// Returns the two [`Rectangle`]s on either side of a split at `ratio` of the way along `axis`.
+    /// The ratio is clamped between `0.0` and `1.0`.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn rects_ratio(&self, axis: Axis, ratio: f32) -> [Rectangle; 2]
+    where T: Metadata {
+        let length = length_along(self.child.bounding_box(), axis);
+        // Also turns NaN into zero, since the cast saturates.
+        let offset = (ratio.clamp(0.0, 1.0) * length as f32).round() as usize;
+
+        self.rects_at(axis, offset)
+    }
+
+    /// Returns `N` [`Rectangle`]s that cover the terminal along `axis`, each as large as its share of
+    /// the total weight. Rounding never loses any cells; if all the weights are zero, so are the sizes.
+    pub fn rects_weighted<const N: usize>(&self, axis: Axis, weights: [usize; N]) -> [Rectangle; N]
+    where T: Metadata {
+        let bounds = self.child.bounding_box();
+        let length = length_along(bounds, axis);
+        let mut split = WeightedSplit::new(length, weights.iter().sum());
+        let mut end = 0;
+
+        let ends = weights.map(|weight| {
+            end += split.piece(weight);
+
+            end
+        });
+
+        pieces(bounds, axis, ends)
+    }
+
+    /// Splits the terminal `offset` cells along `axis`, and returns the [`View`]s on either side.
+    /// See [`ViewSplit::rects_at`].
+    #[allow(clippy::missing_panics_doc)]
+    pub fn split_at(&self, axis: Axis, offset: usize) -> [View<&T>; 2]
+    where T: TerminalConst {
+        self.rects_at(axis, offset).map(|rect| self.child.view(rect).expect("View should've been valid."))
+    }
+
+    /// Splits the terminal at `ratio` of the way along `axis`, and returns the [`View`]s on either
+    /// side. See [`ViewSplit::rects_ratio`].
+    #[allow(clippy::missing_panics_doc)]
+    pub fn split_ratio(&self, axis: Axis, ratio: f32) -> [View<&T>; 2]
+    where T: TerminalConst {
+        self.rects_ratio(axis, ratio).map(|rect| self.child.view(rect).expect("View should've been valid."))
+    }
+
+    /// Splits the terminal into `N` weighted pieces along `axis`, and returns their [`View`]s. See
+    /// [`ViewSplit::rects_weighted`].
+    #[allow(clippy::missing_panics_doc)]
+    pub fn split_weighted<const N: usize>(&self, axis: Axis, weights: [usize; N]) -> [View<&T>; N]
+    where T: TerminalConst {
+        self.rects_weighted(axis, weights).map(|rect| self.child.view(rect).expect("View should've been valid."))
+    }
+
+    /// Returns a [`Pane`] for each of the given [`Rectangle`]s, which can all be drawn into at once.
+    ///
+    /// The references to the cells inside the panes are stored in `buffer`, so it needs to be at least
+    /// as long as the total area of the rectangles. Empty rectangles are allowed, and give empty panes.
+    ///
+    /// Returns `None` if the buffer is too short, or if any of the rectangles overlap or are outside
+    /// of the terminal.
+    pub fn panes_mut<'a, 'b, const N: usize>(&'a mut self, rects: [Rectangle; N], buffer: &'b mut [Option<&'a mut Cell>]) -> Option<[Pane<'a, 'b>; N]>
+    where T: TerminalMut {
+        let bounds = self.child.bounding_box();
+        let default_style = self.child.default_style();
+
+        if buffer.len() < rects.iter().map(Rectangle::area).sum() {
+            return None;
+        }
+
+        for (index, rect) in rects.iter().enumerate() {
+            if !bounds.contains_rect(*rect) || rects[..index].iter().any(|other| overlaps(*rect, *other)) {
+                return None;
+            }
+        }
+
+        let mut starts = [0; N];
+        let mut area_so_far = 0;
+
+        for (start, rect) in starts.iter_mut().zip(rects) {
+            *start = area_so_far;
+            area_so_far += rect.area();
+        }
+
+        let width = bounds.width();
+
+        for (index, cell) in self.child.cells_mut().enumerate() {
+            let (Some(x), Some(y)) = (index.checked_rem(width), index.checked_div(width)) else {
+                break;
+            };
+
+            if let Some((rect, start)) = rects.iter().zip(starts).find(|(rect, _)| rect.contains((x, y))) {
+                buffer[start + (y - rect.top()) * rect.width() + (x - rect.left())] = Some(cell);
+            }
+        }
+
+        let mut rest = buffer;
+
+        Some(rects.map(|rect| {
+            let (cells, tail) = core::mem::take(&mut rest).split_at_mut(rect.area());
+            rest = tail;
+
+            Pane::new(cells, rect.dimensions(), default_style)
+        }))
+    }
+
+    /// Splits the terminal `offset` cells along `axis`, and returns the [`Pane`]s on either side.
+    /// See [`ViewSplit::rects_at`] and [`ViewSplit::panes_mut`].
+    pub fn split_at_mut<'a, 'b>(&'a mut self, axis: Axis, offset: usize, buffer: &'b mut [Option<&'a mut Cell>]) -> Option<[Pane<'a, 'b>; 2]>
+    where T: TerminalMut {
+        let rects = self.rects_at(axis, offset);
+
+        self.panes_mut(rects, buffer)
+    }
+
+    /// Splits the terminal at `ratio` of the way along `axis`, and returns the [`Pane`]s on either
+    /// side. See [`ViewSplit::rects_ratio`] and [`ViewSplit::panes_mut`].
+    pub fn split_ratio_mut<'a, 'b>(&'a mut self, axis: Axis, ratio: f32, buffer: &'b mut [Option<&'a mut Cell>]) -> Option<[Pane<'a, 'b>; 2]>
+    where T: TerminalMut {
+        let rects = self.rects_ratio(axis, ratio);
+
+        self.panes_mut(rects, buffer)
+    }
+
+    /// Splits the terminal into `N` weighted pieces along `axis`, and returns their [`Pane`]s. See
+    /// [`ViewSplit::rects_weighted`] and [`ViewSplit::panes_mut`].
+    pub fn split_weighted_mut<'a, 'b, const N: usize>(&'a mut self, axis: Axis, weights: [usize; N], buffer: &'b mut [Option<&'a mut Cell>]) -> Option<[Pane<'a, 'b>; N]>
+    where T: TerminalMut {
+        let rects = self.rects_weighted(axis, weights);
+
+        self.panes_mut(rects, buffer)
+    }
+
     /// Select a split based on the given [`Direction`].
     ///
     /// - [`Direction::Down`] returns a [`ViewSplit::split_bottom`]
@@ -164,6 +335,65 @@ impl<T> ViewSplit<T> {
     }
 }
 
+/// Splits a length into pieces that are as large as their share of a total weight, one piece at a time.
+///
+/// The pieces are spread out cumulatively, instead of rounding each of their sizes on its own, which
+/// means that rounding never loses any cells. If the total weight is zero, so are the pieces.
+pub(crate) struct WeightedSplit {
+    length: usize,
+    total: usize,
+    weight_so_far: usize,
+}
+
+impl WeightedSplit {
+    /// Create a new [`WeightedSplit`] of `length` into pieces that weigh `total` altogether.
+    pub(crate) const fn new(length: usize, total: usize) -> Self {
+        Self {
+            length,
+            total,
+            weight_so_far: 0,
+        }
+    }
+
+    /// Returns the size of the next piece, given its weight.
+    pub(crate) fn piece(&mut self, weight: usize) -> usize {
+        let start = (self.length * self.weight_so_far).checked_div(self.total).unwrap_or(0);
+        self.weight_so_far += weight;
+
+        (self.length * self.weight_so_far).checked_div(self.total).unwrap_or(0) - start
+    }
+}
+
+/// Returns the size of a [`Rectangle`] along an axis.
+pub(crate) const fn length_along(rect: Rectangle, axis: Axis) -> usize {
+    match axis {
+        Axis::Horizontal => rect.width(),
+        Axis::Vertical => rect.height(),
+    }
+}
+
+/// Cuts `bounds` into consecutive pieces along `axis`, where each piece ends at the given offset
+/// from the start of `bounds`. Offsets are clamped so that the pieces never overlap or leave `bounds`.
+fn pieces<const N: usize>(bounds: Rectangle, axis: Axis, ends: [usize; N]) -> [Rectangle; N] {
+    let length = length_along(bounds, axis);
+    let mut start = 0;
+
+    ends.map(|end| {
+        let (from, to) = (start, end.clamp(start, length));
+        start = to;
+
+        match axis {
+            Axis::Horizontal => Rectangle::new((bounds.left() + from, bounds.top()), (bounds.left() + to, bounds.bottom())),
+            Axis::Vertical => Rectangle::new((bounds.left(), bounds.top() + from), (bounds.right(), bounds.top() + to)),
+        }
+    })
+}
+
+/// Returns whether two [`Rectangle`]s share any cells.
+const fn overlaps(first: Rectangle, second: Rectangle) -> bool {
+    first.left() < second.right() && second.left() < first.right() && first.top() < second.bottom() && second.top() < first.bottom()
+}
+
 impl<T: Metadata> Metadata for ViewSplit<T> {
     fn dimensions(&self) -> (usize, usize) {
         self.child.dimensions()
@@ -198,4 +428,55 @@ impl<T: Rescalable> Rescalable for ViewSplit<T> {
     fn rescale(&mut self, new_size: (usize, usize)) -> Result<(), (usize, usize)> {
         self.child.rescale(new_size)
     }
-}
\ No newline at end of file
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::ViewSplit;
+    use crate::prelude::*;
+    use crate::terminal::{Cell, ConstantSize};
+    use crate::widgets::Axis;
+
+    #[test]
+    fn empty_views() {
+        let terminal: ConstantSize<4, 2> = ConstantSize::new();
+        let split = ViewSplit::new(&terminal);
+
+        let [empty, full] = split.split_ratio(Axis::Horizontal, 0.0);
+        assert_eq!((empty.dimensions(), full.dimensions()), ((0, 2), (4, 2)));
+        assert_eq!(empty.cells().count(), 0);
+        assert_eq!(full.cells().count(), 8);
+
+        let [full, empty] = split.split_ratio(Axis::Horizontal, 1.0);
+        assert_eq!((full.dimensions(), empty.dimensions()), ((4, 2), (0, 2)));
+        assert_eq!(empty.cells().count(), 0);
+
+        let [empty, full] = split.split_at(Axis::Vertical, 0);
+        assert_eq!((empty.dimensions(), full.dimensions()), ((4, 0), (4, 2)));
+        assert_eq!(empty.cells().count(), 0);
+
+        let [empty, full] = split.split_weighted(Axis::Horizontal, [0, 1]);
+        assert_eq!((empty.dimensions(), full.dimensions()), ((0, 2), (4, 2)));
+    }
+
+    #[test]
+    fn empty_panes() {
+        let mut terminal: ConstantSize<4, 2> = ConstantSize::new();
+        let mut split = ViewSplit::new(&mut terminal);
+        let mut buffer = [const { None::<&mut Cell> }; 4 * 2];
+
+        let [mut full, mut empty] = split.split_ratio_mut(Axis::Horizontal, 1.0, &mut buffer).expect("The buffer is large enough");
+
+        assert_eq!(empty.dimensions(), (0, 2));
+        assert!(empty.cell_mut(0, 0).is_none());
+
+        full.cell_mut(3, 1).expect("Cell should exist").character = 'x';
+
+        let mut buffer = [const { None::<&mut Cell> }; 4 * 2];
+        let [empty, full, also_empty] = split.split_weighted_mut(Axis::Vertical, [0, 1, 0], &mut buffer).expect("The buffer is large enough");
+
+        assert_eq!((empty.dimensions(), full.dimensions(), also_empty.dimensions()), ((4, 0), (4, 2), (4, 0)));
+        assert_eq!(full.cell(3, 1).expect("Cell should exist").character, 'x');
+    }
+}
@@ -0,0 +1,222 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::style::Style;
+use crate::terminal::Cell;
+
+/// Returns the number of terminal columns that a character occupies when it is displayed.
+///
+/// - Wide characters, like CJK ideographs and most emoji, take up two columns.
+/// - Zero-width characters, like combining marks and zero-width joiners, take up no columns.
+///   A [`Cell`] can only hold a single [`char`], so these are dropped by [`CellWriter`] and the
+///   builtin widgets. This means that decomposed text loses its accents: `"e\u{301}"` is drawn as
+///   a plain `e`, so use precomposed characters like `'é'` instead.
+/// - Control characters get rendered as a space, so they take up one column.
+/// - [`Cell::CONTINUATION`] takes up no columns, because it is covered by the wide character before it.
+///
+/// ```
+/// use tuit::terminal::width;
+///
+/// assert_eq!(width::of_char('a'), 1);
+/// assert_eq!(width::of_char('日'), 2);
+/// assert_eq!(width::of_char('\u{301}'), 0); // COMBINING ACUTE ACCENT
+/// ```
+#[must_use]
+pub fn of_char(character: char) -> usize {
+    if character == Cell::CONTINUATION {
+        return 0;
+    }
+
+    if character.is_control() {
+        return 1;
+    }
+
+    character.width().unwrap_or(1)
+}
+
+/// Returns the number of terminal columns that a string occupies on a single line.
+///
+/// ```
+/// use tuit::terminal::width;
+///
+/// assert_eq!(width::of_str("Hello"), 5);
+/// assert_eq!(width::of_str("日本語"), 6);
+/// ```
+#[must_use]
+pub fn of_str(text: &str) -> usize {
+    text.chars().map(of_char).sum()
+}
+
+/// Returns the (width, height) in cells that `text` takes up when it is poured into rows that are
+/// `row_width` columns wide.
+///
+/// A wide character never gets split across two rows; it is moved onto the next row instead, just
+/// like [`CellWriter::write`] does.
+///
+/// ```
+/// use tuit::terminal::width;
+///
+/// assert_eq!(width::wrapped_dimensions("Hello!", 4), (4, 2));
+/// // The third character doesn't fit on the first row, so a column is left empty.
+/// assert_eq!(width::wrapped_dimensions("日本語", 5), (4, 2));
+/// ```
+#[must_use]
+pub fn wrapped_dimensions(text: &str, row_width: usize) -> (usize, usize) {
+    let mut column = 0;
+    let mut widest = 0;
+    let mut rows = 0;
+
+    for width in text.chars().map(of_char) {
+        if width == 0 || width > row_width {
+            continue;
+        }
+
+        if rows == 0 || column + width > row_width {
+            rows += 1;
+            column = 0;
+        }
+
+        column += width;
+        widest = widest.max(column);
+    }
+
+    (widest, rows)
+}
+
+/// Writes characters into a row-major sequence of [`Cell`]s, while taking each character's
+/// display width into account.
+///
+/// Wide characters take up two cells; the second cell is filled with [`Cell::CONTINUATION`]. If a wide
+/// character does not fit on the rest of the current row, it is moved to the start of the next row.
+///
+/// Zero-width characters, like combining marks, variation selectors and zero-width joiners, can't be
+/// attached to the character before them because a [`Cell`] only holds a single [`char`]. They are
+/// dropped instead, so an accent written as a separate combining mark gets lost, and emoji sequences
+/// are drawn as their separate parts. See [`of_char`].
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Style;
+/// use tuit::terminal::{Cell, ConstantSize};
+/// use tuit::terminal::width::CellWriter;
+///
+/// let mut terminal: ConstantSize<3, 2> = ConstantSize::new();
+/// let width = terminal.width();
+/// let mut writer = CellWriter::new(terminal.cells_mut(), width);
+///
+/// writer.write('a', Style::new()).expect("There is enough space");
+/// writer.write('日', Style::new()).expect("There is enough space");
+/// writer.write('本', Style::new()).expect("There is enough space");
+/// drop(writer);
+///
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, 'a');
+/// assert_eq!(terminal.cell(1, 0).unwrap().character, '日');
+/// assert!(terminal.cell(2, 0).unwrap().is_continuation());
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, '本');
+/// ```
+///
+/// A combining mark is dropped rather than attached to the character before it:
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Style;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::terminal::width::CellWriter;
+///
+/// let mut terminal: ConstantSize<2, 1> = ConstantSize::new();
+/// let mut writer = CellWriter::new(terminal.cells_mut(), 2);
+///
+/// for character in "e\u{301}x".chars() {
+///     writer.write(character, Style::new()).expect("There is enough space");
+/// }
+/// drop(writer);
+///
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, 'e');
+/// assert_eq!(terminal.cell(1, 0).unwrap().character, 'x');
+/// ```
+#[derive(Debug)]
+pub struct CellWriter<I> {
+    cells: I,
+    row_width: usize,
+    column: usize,
+}
+
+impl<'a, I: Iterator<Item = &'a mut Cell>> CellWriter<I> {
+    /// Create a new [`CellWriter`] from a row-major iterator over [`Cell`]s, like the one returned
+    /// by [`TerminalMut::cells_mut`](crate::terminal::TerminalMut::cells_mut).
+    pub const fn new(cells: I, row_width: usize) -> Self {
+        Self {
+            cells,
+            row_width,
+            column: 0,
+        }
+    }
+
+    /// Returns the column that the next character will be written to.
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Skips over the next cell without modifying it.
+    ///
+    /// Returns `None` if there are no cells left.
+    pub fn skip(&mut self) -> Option<()> {
+        self.advance().map(|_| ())
+    }
+
+    /// Skips over the rest of the current row without modifying it. Does nothing if the writer is
+    /// already at the start of a row.
+    ///
+    /// Returns `None` if there are no cells left.
+    pub fn next_row(&mut self) -> Option<()> {
+        while self.column != 0 {
+            self.skip()?;
+        }
+
+        Some(())
+    }
+
+    /// Writes a character into the next cell(s), applying `style` on top of the cell's existing style.
+    ///
+    /// Zero-width characters (see [`CellWriter`]) are skipped, and wide characters that are wider
+    /// than an entire row are dropped.
+    ///
+    /// Returns `None` if there aren't enough cells left to write the character.
+    pub fn write(&mut self, character: char, style: Style) -> Option<()> {
+        let width = of_char(character);
+
+        if width == 0 || width > self.row_width {
+            return Some(());
+        }
+
+        if self.column + width > self.row_width {
+            self.next_row()?;
+        }
+
+        let cell = self.advance()?;
+
+        cell.character = character;
+        cell.style = style.inherits(cell.style);
+
+        for _ in 1..width {
+            let cell = self.advance()?;
+
+            cell.character = Cell::CONTINUATION;
+            cell.style = style.inherits(cell.style);
+        }
+
+        Some(())
+    }
+
+    fn advance(&mut self) -> Option<&'a mut Cell> {
+        let cell = self.cells.next()?;
+
+        self.column += 1;
+
+        if self.column >= self.row_width {
+            self.column = 0;
+        }
+
+        Some(cell)
+    }
+}
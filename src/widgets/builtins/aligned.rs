@@ -0,0 +1,190 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+use crate::Error;
+
+/// A point inside of a widget's space that an [`Aligned`] widget lines its child up against.
+///
+/// Both coordinates are fractions of the free space around the child, so `0.0` is the left or top
+/// edge, `0.5` is the middle and `1.0` is the right or bottom edge. They're clamped between `0.0`
+/// and `1.0`.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Anchor {
+    /// How far along the free space the child is placed horizontally.
+    pub x: f32,
+    /// How far along the free space the child is placed vertically.
+    pub y: f32,
+}
+
+impl Anchor {
+    /// The top-left corner.
+    pub const TOP_LEFT: Self = Self::new(0.0, 0.0);
+    /// The middle of the top edge.
+    pub const TOP: Self = Self::new(0.5, 0.0);
+    /// The top-right corner.
+    pub const TOP_RIGHT: Self = Self::new(1.0, 0.0);
+    /// The middle of the left edge.
+    pub const LEFT: Self = Self::new(0.0, 0.5);
+    /// The middle, like [`Centered`](super::Centered).
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+    /// The middle of the right edge.
+    pub const RIGHT: Self = Self::new(1.0, 0.5);
+    /// The bottom-left corner.
+    pub const BOTTOM_LEFT: Self = Self::new(0.0, 1.0);
+    /// The middle of the bottom edge.
+    pub const BOTTOM: Self = Self::new(0.5, 1.0);
+    /// The bottom-right corner.
+    pub const BOTTOM_RIGHT: Self = Self::new(1.0, 1.0);
+
+    /// Create a new [`Anchor`] out of fractions of the free space.
+    #[must_use]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns how far to move a child that is `size` cells long into `space` cells, along an axis
+    /// with the given fraction.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn place(fraction: f32, size: usize, space: usize) -> usize {
+        let free = space.saturating_sub(size);
+
+        // Also turns NaN into zero, since the cast saturates.
+        (fraction.clamp(0.0, 1.0) * free as f32).round() as usize
+    }
+}
+
+/// A widget that places its child at an [`Anchor`] within its bounding box, and then moves it by
+/// an offset.
+///
+/// Child widgets need to implement [`BoundingBox`]. The offset never moves the child outside of
+/// the space that it's given.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::Alignment;
+/// use tuit::widgets::builtins::{Anchor, Paragraph, Text};
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+///
+/// Text::new("Saved").anchored(Anchor::BOTTOM_RIGHT).offset(-1, 0).drawn(&mut terminal).expect("Should draw successfully");
+/// Text::new("Title").anchored(Anchor::TOP).drawn(&mut terminal).expect("Should draw successfully");
+///
+/// // The status sits in the bottom-right corner, one cell away from the right edge...
+/// assert_eq!(terminal.cell(14, 4).unwrap().character, 'S');
+/// assert_eq!(terminal.cell(18, 4).unwrap().character, 'd');
+/// // ...and the title is centred along the top.
+/// assert_eq!(terminal.cell(8, 0).unwrap().character, 'T');
+///
+/// // Widgets that align their own text can be anchored too.
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+///
+/// Paragraph::new("Hi").aligned(Alignment::Right).anchored(Anchor::BOTTOM_RIGHT).drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(19, 4).unwrap().character, 'i');
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Aligned<T> {
+    child: T,
+    /// Where the child is placed.
+    pub anchor: Anchor,
+    /// How many cells the child is moved right and down after it's been placed. Negative values move it
+    /// left and up.
+    pub offset: (isize, isize),
+}
+
+impl<T> Aligned<T> {
+    /// Make a new [`Aligned`] widget that places its child at the given [`Anchor`].
+    pub const fn new(child: T, anchor: Anchor) -> Self {
+        Self {
+            child,
+            anchor,
+            offset: (0, 0),
+        }
+    }
+
+    /// Move the child by the given number of cells after it's been placed.
+    #[must_use]
+    pub const fn offset(mut self, x: isize, y: isize) -> Self {
+        self.offset = (x, y);
+
+        self
+    }
+
+    /// Consume the [`Aligned`] widget and return the inner widget.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.child
+    }
+
+    /// Get a reference to the inner widget.
+    #[must_use]
+    pub const fn inner(&self) -> &T {
+        &self.child
+    }
+
+    /// Get a mutable reference to the inner widget.
+    pub const fn inner_mut(&mut self) -> &mut T {
+        &mut self.child
+    }
+}
+
+/// Moves `position` by `offset`, without going below `min` or above `max`.
+const fn nudge(position: usize, offset: isize, min: usize, max: usize) -> usize {
+    let moved = position.saturating_add_signed(offset);
+
+    if moved < min {
+        min
+    } else if moved > max {
+        max
+    } else {
+        moved
+    }
+}
+
+impl<T: BoundingBox> Widget for Aligned<T> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let bounding_box = self.bounding_box_in(&terminal)?;
+        let view = terminal.view(bounding_box).ok_or_else(|| Error::rescale_to(bounding_box))?;
+
+        self.child.update(update_info.mouse_relative_to(bounding_box), view)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let bounding_box = self.bounding_box_in(&terminal)?;
+        let view = terminal.view_mut(bounding_box).ok_or_else(|| Error::rescale_to(bounding_box))?;
+
+        self.child.draw(view)
+    }
+
+    fn focusable(&self) -> usize {
+        self.child.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.child.set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.child.focused()
+    }
+}
+
+impl<T: BoundingBox> BoundingBox for Aligned<T> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let (width, height) = self.child.bounding_box(rect)?.dimensions();
+        let (offset_x, offset_y) = self.offset;
+
+        let left = rect.left() + Anchor::place(self.anchor.x, width, rect.width());
+        let top = rect.top() + Anchor::place(self.anchor.y, height, rect.height());
+
+        let left = nudge(left, offset_x, rect.left(), rect.right().saturating_sub(width).max(rect.left()));
+        let top = nudge(top, offset_y, rect.top(), rect.bottom().saturating_sub(height).max(rect.top()));
+
+        Ok(Rectangle::of_size((width, height)).at((left, top)))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        self.child.completely_covers(rectangle)
+    }
+}
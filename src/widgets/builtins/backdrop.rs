@@ -36,7 +36,7 @@ impl<'a, T> Backdrop<'a, T> {
     }
 }
 
-impl<'a, T> Widget for Backdrop<'a, T>
+impl<T> Widget for Backdrop<'_, T>
 where T: BoundingBox{
     fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
         Ok(UpdateResult::NoEvent)
@@ -0,0 +1,361 @@
+use crate::prelude::{Metadata, Terminal, TerminalConst};
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Cell, Rectangle, TerminalMut, UpdateInfo, UpdateResult, View};
+use crate::widgets::{Alignment, BoundingBox, Widget};
+use crate::Error;
+
+/// The characters that a [`Border`] is drawn with.
+///
+/// Tuit provides a few common sets of box-drawing characters, but you can also make your own.
+/// The corners and edges are meant to be one cell wide. Wider horizontal edges are repeated as
+/// many times as they fit, and any cells that are left over (or that a character can't be drawn
+/// in at all, like a zero-width one) are left blank.
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Outline {
+    /// The top-left corner.
+    pub top_left: char,
+    /// The top-right corner.
+    pub top_right: char,
+    /// The bottom-left corner.
+    pub bottom_left: char,
+    /// The bottom-right corner.
+    pub bottom_right: char,
+    /// The top and bottom edges.
+    pub horizontal: char,
+    /// The left and right edges.
+    pub vertical: char,
+}
+
+impl Outline {
+    /// A thin outline with square corners: `┌─┐`.
+    pub const SINGLE: Self = Self::new(['┌', '┐', '└', '┘', '─', '│']);
+    /// A doubled outline: `╔═╗`.
+    pub const DOUBLE: Self = Self::new(['╔', '╗', '╚', '╝', '═', '║']);
+    /// A thin outline with rounded corners: `╭─╮`.
+    pub const ROUNDED: Self = Self::new(['╭', '╮', '╰', '╯', '─', '│']);
+    /// A thick outline: `┏━┓`.
+    pub const THICK: Self = Self::new(['┏', '┓', '┗', '┛', '━', '┃']);
+    /// An outline made only of ASCII characters, for terminals without box-drawing characters: `+-+`.
+    pub const ASCII: Self = Self::new(['+', '+', '+', '+', '-', '|']);
+
+    /// Create a new [`Outline`] from its top-left, top-right, bottom-left and bottom-right corners,
+    /// followed by its horizontal and vertical edges.
+    #[must_use]
+    pub const fn new([top_left, top_right, bottom_left, bottom_right, horizontal, vertical]: [char; 6]) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+impl Default for Outline {
+    fn default() -> Self {
+        Self::SINGLE
+    }
+}
+
+/// Draws a border around a [`BoundingBox`] widget, with an optional title on the top edge.
+///
+/// The border hugs the child's bounding box, and the child is given a [`View`] of the space inside
+/// the border to draw in and receive updates from. The border is widened to fit its title if
+/// there's room.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::Alignment;
+/// use tuit::widgets::builtins::{Outline, Text};
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+///
+/// let framed = Text::new("eth0 up").bordered(Outline::ROUNDED).titled("NIC").title_aligned(Alignment::Center);
+///
+/// framed.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, '╭');
+/// assert_eq!(terminal.cell(3, 0).unwrap().character, 'N');
+/// assert_eq!(terminal.cell(8, 0).unwrap().character, '╮');
+/// assert_eq!(terminal.cell(1, 1).unwrap().character, 'e');
+/// assert_eq!(terminal.cell(8, 2).unwrap().character, '╯');
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Border<'a, T> {
+    /// The child widget.
+    child: T,
+    /// The characters that the border is drawn with.
+    pub outline: Outline,
+    /// The style of the border.
+    pub style: Style,
+    /// The title drawn on the top edge of the border. No title is drawn if it's empty.
+    pub title: &'a str,
+    /// Where the title is placed on the top edge. [`Alignment::Justified`] is treated like
+    /// [`Alignment::Left`].
+    pub title_alignment: Alignment,
+    /// The style of the title. Properties that aren't set are taken from [`Border::style`].
+    pub title_style: Style,
+}
+
+impl<'a, T> Border<'a, T> {
+    /// Create a new [`Border`] around a child widget, drawn with [`Outline::SINGLE`] and without a title.
+    #[must_use]
+    pub const fn new(child: T) -> Self {
+        Self {
+            child,
+            outline: Outline::SINGLE,
+            style: Style::new(),
+            title: "",
+            title_alignment: Alignment::Left,
+            title_style: Style::new(),
+        }
+    }
+
+    /// Set the characters that the [`Border`] is drawn with.
+    #[must_use]
+    pub const fn outline(mut self, outline: Outline) -> Self {
+        self.outline = outline;
+
+        self
+    }
+
+    /// Set the style of the [`Border`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the title drawn on the top edge of the [`Border`].
+    #[must_use]
+    pub const fn titled(mut self, title: &'a str) -> Self {
+        self.title = title;
+
+        self
+    }
+
+    /// Set where the title is placed on the top edge of the [`Border`].
+    #[must_use]
+    pub const fn title_aligned(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
+
+        self
+    }
+
+    /// Set the style of the title.
+    #[must_use]
+    pub const fn title_styled(mut self, style: Style) -> Self {
+        self.title_style = style;
+
+        self
+    }
+
+    /// Get a reference to the child widget.
+    pub const fn inner(&self) -> &T {
+        &self.child
+    }
+
+    /// Get a mutable reference to the child widget.
+    pub const fn inner_mut(&mut self) -> &mut T {
+        &mut self.child
+    }
+
+    /// Consume the [`Border`] and return the child widget.
+    pub fn into_inner(self) -> T {
+        self.child
+    }
+
+    /// Returns the space inside the border.
+    fn inner_rect(&self, terminal: impl Metadata) -> crate::Result<Rectangle>
+    where T: BoundingBox {
+        let rect = self.bounding_box_in(terminal)?;
+
+        Ok(Rectangle::new((rect.left() + 1, rect.top() + 1), (rect.right() - 1, rect.bottom() - 1)))
+    }
+
+    /// Draws the top edge of the border, along with the title, into a single-row `area`.
+    fn draw_top_edge(&self, terminal: &mut impl Terminal, area: Rectangle) -> crate::Result<()> {
+        let edge_width = area.width() - 2;
+        let title_width = width::of_str(self.title).min(edge_width);
+        let padding = match self.title_alignment {
+            Alignment::Left | Alignment::Justified => 0,
+            Alignment::Center => (edge_width - title_width) / 2,
+            Alignment::Right => edge_width - title_width,
+        };
+
+        let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+        let mut writer = CellWriter::new(view.cells_mut(), area.width());
+        let title_style = self.title_style.inherits(self.style);
+        let mut written = 0;
+
+        write_repeated(&mut writer, self.outline.top_left, 1, self.style);
+        write_repeated(&mut writer, self.outline.horizontal, padding, self.style);
+
+        for character in self.title.chars() {
+            let character_width = width::of_char(character);
+
+            if written + character_width > title_width {
+                break;
+            }
+
+            writer.write(character, title_style);
+            written += character_width;
+        }
+
+        // Also fills in the cell that a wide character of the title didn't fit in.
+        write_repeated(&mut writer, self.outline.horizontal, edge_width - padding - written, self.style);
+        write_repeated(&mut writer, self.outline.top_right, 1, self.style);
+
+        Ok(())
+    }
+
+    /// Draws the bottom edge of the border into a single-row `area`.
+    fn draw_bottom_edge(&self, terminal: &mut impl Terminal, area: Rectangle) -> crate::Result<()> {
+        let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+        let mut writer = CellWriter::new(view.cells_mut(), area.width());
+
+        write_repeated(&mut writer, self.outline.bottom_left, 1, self.style);
+        write_repeated(&mut writer, self.outline.horizontal, area.width() - 2, self.style);
+        write_repeated(&mut writer, self.outline.bottom_right, 1, self.style);
+
+        Ok(())
+    }
+}
+
+/// Writes `character` as many times as it fits into the next `cells` cells, and fills the rest of
+/// them with spaces.
+fn write_repeated<'a>(writer: &mut CellWriter<impl Iterator<Item = &'a mut Cell>>, character: char, cells: usize, style: Style) {
+    let character_width = width::of_char(character);
+    let repeats = cells.checked_div(character_width).unwrap_or(0);
+
+    for _ in 0..repeats {
+        writer.write(character, style);
+    }
+
+    for _ in repeats * character_width..cells {
+        writer.write(' ', style);
+    }
+}
+
+impl<T: BoundingBox> Widget for Border<'_, T> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let inner_rect = self.inner_rect(&terminal)?;
+        let view = View::new(terminal, inner_rect).ok_or_else(|| Error::rescale_to(inner_rect))?;
+
+        self.child.update(update_info.mouse_relative_to(inner_rect), view)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let rect = self.bounding_box_in(&terminal)?;
+        let ((left, top), (right, bottom)) = (rect.left_top(), rect.right_bottom());
+
+        self.draw_top_edge(&mut terminal, Rectangle::new((left, top), (right, top + 1)))?;
+
+        for y in top + 1..bottom - 1 {
+            for x in [left, right - 1] {
+                let mut view = terminal.view_mut(Rectangle::new((x, y), (x + 1, y + 1))).ok_or_else(|| Error::oob_with((x, y)))?;
+
+                write_repeated(&mut CellWriter::new(view.cells_mut(), 1), self.outline.vertical, 1, self.style);
+            }
+        }
+
+        self.draw_bottom_edge(&mut terminal, Rectangle::new((left, bottom - 1), (right, bottom)))?;
+
+        let inner_rect = Rectangle::new((left + 1, top + 1), (right - 1, bottom - 1));
+        let view = View::new(terminal, inner_rect).ok_or_else(|| Error::rescale_to(inner_rect))?;
+
+        self.child.draw(view)
+    }
+
+    fn focusable(&self) -> usize {
+        self.child.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.child.set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.child.focused()
+    }
+}
+
+impl<T: BoundingBox> BoundingBox for Border<'_, T> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let (width, height) = rect.dimensions();
+
+        if width < 2 || height < 2 {
+            return Err(Error::rescale((width.max(2), height.max(2))));
+        }
+
+        // The child's bounding box is relative to the space inside of the border.
+        let child = self.child.bounding_box(Rectangle::of_size((width - 2, height - 2)))?;
+        let title_width = (width::of_str(self.title) + 2).min(width);
+
+        let left_top = (rect.left() + child.left(), rect.top() + child.top());
+        let right = (left_top.0 + child.width() + 2).max(left_top.0 + title_width).min(rect.right());
+
+        Ok(Rectangle::new(left_top, (right, left_top.1 + child.height() + 2)))
+    }
+
+    // The border does not draw over the space around it,
+    // so it does not completely cover the [`Rectangle`].
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        false
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::Outline;
+    use crate::prelude::*;
+    use crate::terminal::{Cell, ConstantSize};
+    use crate::widgets::builtins::Text;
+
+    #[test]
+    fn zero_width_horizontal_edge() {
+        let mut terminal: ConstantSize<4, 3> = ConstantSize::new();
+        let outline = Outline::new(['+', '+', '+', '+', '\u{200B}', '|']);
+
+        Text::new("ab").bordered(outline).drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters[0].map(|cell| cell.character), ['+', ' ', ' ', '+']);
+        assert_eq!(terminal.characters[2].map(|cell| cell.character), ['+', ' ', ' ', '+']);
+    }
+
+    #[test]
+    fn wide_horizontal_edge() {
+        let mut terminal: ConstantSize<5, 3> = ConstantSize::new();
+        let outline = Outline::new(['+', '+', '+', '+', '＝', '|']);
+
+        Text::new("abc").bordered(outline).drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters[0].map(|cell| cell.character), ['+', '＝', Cell::CONTINUATION, ' ', '+']);
+        assert_eq!(terminal.characters[1].map(|cell| cell.character), ['|', 'a', 'b', 'c', '|']);
+    }
+
+    #[test]
+    fn wide_vertical_edge() {
+        let mut terminal: ConstantSize<4, 3> = ConstantSize::new();
+        let outline = Outline::new(['+', '+', '+', '+', '-', '｜']);
+
+        Text::new("ab").bordered(outline).drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters[1].map(|cell| cell.character), [' ', 'a', 'b', ' ']);
+    }
+
+    #[test]
+    fn wide_title() {
+        let mut terminal: ConstantSize<5, 3> = ConstantSize::new();
+
+        Text::new("abc").bordered(Outline::ASCII).titled("日本").drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters[0].map(|cell| cell.character), ['+', '日', Cell::CONTINUATION, '-', '+']);
+    }
+}
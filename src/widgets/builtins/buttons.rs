@@ -1,5 +1,6 @@
 use crate::style::Style;
-use crate::terminal::{Rectangle, Terminal, TerminalConst, UpdateInfo, UpdateResult};
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Key, KeyState, Modifiers, MouseButton, Rectangle, Terminal, TerminalConst, TerminalMut, UpdateInfo, UpdateResult};
 use crate::widgets::{BoundingBox, Widget};
 use crate::Error;
 
@@ -14,6 +15,8 @@ pub struct Buttons<'a, T> {
     pub unselected_button_style: Style,
     /// The index of the currently hovered button.
     pub hovered_button: Option<usize>,
+    /// The index of the button that was activated during the last update, if any.
+    pub activated_button: Option<usize>,
 }
 
 impl<'a, T: AsRef<str>> Buttons<'a, T> {
@@ -25,6 +28,7 @@ impl<'a, T: AsRef<str>> Buttons<'a, T> {
             selected_button_style: Style::new(),
             unselected_button_style: Style::new(),
             hovered_button: None,
+            activated_button: None,
         }
     }
 
@@ -34,6 +38,38 @@ impl<'a, T: AsRef<str>> Buttons<'a, T> {
         self.hovered_button
     }
 
+    #[must_use]
+    /// Returns the button that was activated (by pressing Enter or Space, or by clicking on it) during
+    /// the last call to [`Widget::update`]. Will be `None` if no button was activated.
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::{ConstantSize, Key, KeyState, MouseButton, UpdateInfo, UpdateResult};
+    /// use tuit::widgets::builtins::Buttons;
+    ///
+    /// let terminal: ConstantSize<20, 1> = ConstantSize::new();
+    /// let mut buttons = Buttons::new(&[" Yes ", " No "]).select_first();
+    ///
+    /// let result = buttons.update(UpdateInfo::KeyboardInput(Key::Right, KeyState::KeyDown), &terminal).unwrap();
+    ///
+    /// assert_eq!(result, UpdateResult::Interacted);
+    /// assert_eq!(buttons.selected(), Some(1));
+    /// assert_eq!(buttons.activated(), None);
+    ///
+    /// buttons.update(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown), &terminal).unwrap();
+    ///
+    /// assert_eq!(buttons.activated(), Some(1));
+    ///
+    /// // " Yes " occupies the first five columns.
+    /// buttons.update(UpdateInfo::CellClicked(2, 0, MouseButton::Primary), &terminal).unwrap();
+    ///
+    /// assert_eq!(buttons.selected(), Some(0));
+    /// assert_eq!(buttons.activated(), Some(0));
+    /// ```
+    pub const fn activated(&self) -> Option<usize> {
+        self.activated_button
+    }
+
     /// Select a button based on its order from left-to-right.
     #[must_use]
     pub const fn select(mut self, selection: usize) -> Self {
@@ -113,25 +149,113 @@ impl<'a, T: AsRef<str>> Buttons<'a, T> {
 
         self.select(selected)
     }
+
+    /// Hovers over the button after the hovered one, or the first button if none are hovered.
+    ///
+    /// If `wrap` is set, moving past the last button goes back to the first one.
+    const fn hover_next(&mut self, wrap: bool) {
+        let last = self.buttons.len().saturating_sub(1);
+
+        self.hovered_button = Some(match self.hovered_button {
+            None => 0,
+            Some(hovered) if hovered >= last => if wrap { 0 } else { last },
+            Some(hovered) => hovered + 1,
+        });
+    }
+
+    /// Hovers over the button before the hovered one, or the last button if none are hovered.
+    ///
+    /// If `wrap` is set, moving past the first button goes to the last one.
+    const fn hover_previous(&mut self, wrap: bool) {
+        let last = self.buttons.len().saturating_sub(1);
+
+        self.hovered_button = Some(match self.hovered_button {
+            None => last,
+            Some(0) if wrap => last,
+            Some(hovered) => hovered.saturating_sub(1),
+        });
+    }
+
+    /// Lays the buttons out left-to-right in rows that are `row_width` columns wide, yielding each
+    /// button's index alongside the area that it occupies.
+    ///
+    /// A button moves to the next row if it doesn't fit on the rest of the current one, and buttons
+    /// that are wider than an entire row get truncated.
+    fn layout(&self, row_width: usize) -> impl Iterator<Item = (usize, Rectangle)> + '_ {
+        let (mut x, mut y) = (0, 0);
+
+        self.buttons.iter().enumerate().map(move |(button_idx, button)| {
+            let width = width::of_str(button.as_ref()).min(row_width);
+
+            if x > 0 && x + width > row_width {
+                x = 0;
+                y += 1;
+            }
+
+            let area = Rectangle::new((x, y), (x + width, y + 1));
+
+            x += width;
+
+            (button_idx, area)
+        })
+    }
 }
 
 impl<T: AsRef<str>> Widget for Buttons<'_, T> {
     fn update(
         &mut self,
-        _update_info: UpdateInfo,
-        _terminal: impl TerminalConst,
+        update_info: UpdateInfo,
+        terminal: impl TerminalConst,
     ) -> crate::Result<UpdateResult> {
-        Err(Error::Todo)
+        self.activated_button = None;
+
+        if self.buttons.is_empty() {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        let previous_hover = self.hovered_button;
+
+        match update_info {
+            // Holding the key down doesn't activate the button again.
+            UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown) | UpdateInfo::KeyboardCharacter(' ', KeyState::KeyDown) => {
+                self.activated_button = self.hovered_button;
+            }
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => match key {
+                Key::Right | Key::Down => self.hover_next(false),
+                Key::Left | Key::Up => self.hover_previous(false),
+                Key::Tab => self.hover_next(true),
+                _ => {}
+            },
+            UpdateInfo::ModifiedKeyboardInput(Key::Tab, Modifiers::SHIFT, KeyState::KeyDown | KeyState::KeyHeld) => {
+                self.hover_previous(true);
+            }
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) => {
+                let clicked = self
+                    .layout(terminal.width())
+                    .find(|(_button_idx, area)| area.contains((x, y)));
+
+                if let Some((button_idx, _area)) = clicked {
+                    self.hovered_button = Some(button_idx);
+                    self.activated_button = Some(button_idx);
+                }
+            }
+            _ => {}
+        }
+
+        if self.activated_button.is_some() || self.hovered_button != previous_hover {
+            Ok(UpdateResult::Interacted)
+        } else {
+            Ok(UpdateResult::NoEvent)
+        }
     }
 
     fn draw(
         &self,
         mut terminal: impl Terminal,
     ) -> crate::Result<UpdateResult> {
-        let term_bounding_box = terminal.bounding_box();
-        let mut terminal_cells = terminal.cells_mut().enumerate().peekable();
+        let row_width = terminal.width();
 
-        for (button_idx, button) in self.buttons.iter().enumerate() {
+        for (button_idx, area) in self.layout(row_width) {
             let selected = Some(button_idx) == self.hovered_button;
             let base_style = if selected {
                 self.selected_button_style
@@ -139,140 +263,86 @@ impl<T: AsRef<str>> Widget for Buttons<'_, T> {
                 self.unselected_button_style
             };
 
-            let max_len = button.as_ref().len().min(term_bounding_box.width());
-
-            let (next_idx, _next_cell) = terminal_cells
-                .peek()
-                .ok_or(Error::OutOfBoundsCoordinate { x: None, y: None })?;
-
-            let (cursor_x, cursor_y) = term_bounding_box
-                .index_into(*next_idx)
-                .ok_or(Error::OutOfBoundsIndex(*next_idx))?;
+            let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+            let mut writer = CellWriter::new(view.cells_mut(), area.width());
 
-            let button_chars = button.as_ref()[..max_len].chars();
-
-            if button.as_ref().len() + cursor_x > term_bounding_box.width() {
-                // Skips until next line, only if the button fits on one line.
-                if button.as_ref().len() <= term_bounding_box.width() {
-                    while let Some((idx, _cell)) = terminal_cells.peek() {
-                        let (_x, y) = term_bounding_box
-                            .index_into(*idx)
-                            .ok_or(Error::OutOfBoundsIndex(*idx))?;
-
-                        if y != cursor_y {
-                            break;
-                        }
-
-                        terminal_cells.next();
-                    }
+            for current_character in self.buttons[button_idx].as_ref().chars() {
+                // Buttons that are wider than the terminal get truncated.
+                if writer.write(current_character, base_style).is_none() {
+                    break;
                 }
-                // If it does not fit on one line, then we need to truncate the button.
-                // We already did this above, so we don't need to do it again.
-            }
-
-            for current_character in button_chars {
-                let (_idx, current_cell) = terminal_cells
-                    .next()
-                    .ok_or(Error::OutOfBoundsCoordinate { x: None, y: None })?;
-
-                current_cell.character = current_character;
-                current_cell.style = base_style.inherits(current_cell.style);
             }
         }
 
         Ok(UpdateResult::NoEvent)
     }
+
+    fn focusable(&self) -> usize {
+        usize::from(!self.buttons.is_empty())
+    }
 }
 
 impl<T: AsRef<str>> BoundingBox for Buttons<'_, T> {
     fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
-        let term_bounding_box = rect;
-
         let (mut width, mut height) = (0, 0);
-        let mut idx = 0;
-
-        height += 1; // Account for the first line.
-
-        // FIXME: Optimize this so that it doesn't have to do a big ugly for loop.
-        // We literally just copy the code from the draw method, but change it so that it logs
-        // the furthest out x and y coordinates. This is probably not the most efficient way to do
-        // this, but it's the only way I can think of right now.
-        for button in self.buttons {
-            let max_len = button.as_ref().len().min(term_bounding_box.width());
-            let next_idx = idx + 1;
-
-            let (cursor_x, cursor_y) = rect
-                .index_into(next_idx)
-                .ok_or(Error::OutOfBoundsIndex(next_idx))?;
-
-            let button_chars = button.as_ref()[..max_len].chars().enumerate();
-
-            if button.as_ref().len() + cursor_x > rect.width() {
-                // Skips until next line, only if the button fits on one line.
-                if button.as_ref().len() <= rect.width() {
-                    while let Some((_x, y)) = rect.index_into(idx) {
-                        if y != cursor_y {
-                            break;
-                        }
-
-                        idx += 1;
-                    }
-                }
-                // If it does not fit on one line, then we need to truncate the button.
-                // We already did this above, so we don't need to do it again.
-            }
-
-            for _current_character in button_chars {
-                idx += 1;
 
-                let (x, y) = rect
-                    .index_into(idx)
-                    .ok_or(Error::OutOfBoundsIndex(idx))?;
-
-                width = width.max(x + 1);
-                height = height.max(y);
-            }
-
-            let (x, y) = rect
-                .index_into(idx)
-                .ok_or(Error::OutOfBoundsIndex(idx))?;
+        for (_button_idx, area) in self.layout(rect.width()) {
+            width = width.max(area.right());
+            height = height.max(area.bottom());
+        }
 
-            width = width.max(x);
-            height = height.max(y);
+        if height > rect.height() {
+            return Err(Error::oob_with((width, height)));
         }
 
         Ok(Rectangle::of_size((width, height)))
     }
 
     fn completely_covers(&self, rectangle: Rectangle) -> bool {
-        let term_bounding_box = rectangle;
-        let mut idx = 0;
-
-        // FIXME: Optimize this so that it doesn't have to do a big ugly for loop.
-        // We literally just copy the code from the draw method, but change it so that diverges
-        // early if any Cell has been skipped.
-        for button in self.buttons {
-            let max_len = button.as_ref().len().min(term_bounding_box.width());
-
-            let mut button_chars = button.as_ref()[..max_len].chars().enumerate().peekable();
-
-            while let Some((chr_dep, _character)) = button_chars.peek() {
-                let chr_dep = *chr_dep;
-                idx += 1;
-                let Some((x, _y)) = term_bounding_box.index_into(idx) else {
-                    return true;
-                };
-
-                if button.as_ref().len() + x - chr_dep >= term_bounding_box.width() {
-                    if button.as_ref().len() < term_bounding_box.width() {
-                        return false;
-                    }
-                }
+        let mut covered = 0;
 
-                button_chars.next().expect("This should always be Some");
+        for (_button_idx, area) in self.layout(rectangle.width()) {
+            // Any gap left at the end of a row by a button moving to the next row is not covered.
+            if area.left() == 0 && covered % rectangle.width().max(1) != 0 {
+                return false;
             }
+
+            covered += area.width();
         }
 
-        true
+        covered >= rectangle.area()
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::Buttons;
+    use crate::prelude::*;
+    use crate::terminal::{ConstantSize, Key, KeyState, UpdateInfo, UpdateResult};
+
+    #[test]
+    fn held_enter_activates_once() {
+        let terminal: ConstantSize<20, 1> = ConstantSize::new();
+        let mut buttons = Buttons::new(&[" Yes ", " No "]).select_first();
+
+        buttons.update(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown), &terminal).expect("Should update successfully");
+        assert_eq!(buttons.activated(), Some(0));
+
+        let result = buttons.update(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyHeld), &terminal).expect("Should update successfully");
+        assert_eq!(result, UpdateResult::NoEvent);
+        assert_eq!(buttons.activated(), None);
+    }
+
+    #[test]
+    fn held_arrows_keep_moving() {
+        let terminal: ConstantSize<20, 1> = ConstantSize::new();
+        let mut buttons = Buttons::new(&["A", "B", "C"]).select_first();
+
+        buttons.update(UpdateInfo::KeyboardInput(Key::Right, KeyState::KeyDown), &terminal).expect("Should update successfully");
+        buttons.update(UpdateInfo::KeyboardInput(Key::Right, KeyState::KeyHeld), &terminal).expect("Should update successfully");
+
+        assert_eq!(buttons.selected(), Some(2));
+        assert_eq!(buttons.activated(), None);
     }
 }
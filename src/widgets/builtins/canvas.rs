@@ -0,0 +1,446 @@
+use crate::prelude::{Terminal, TerminalConst};
+use crate::style::{Colour, Style};
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::{BoundingBox, Widget};
+use crate::Error;
+
+/// The bits of a braille pattern that each dot of a 2x4 cell corresponds to, indexed by `[y][x]`.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+/// The half-block characters, indexed by their top and bottom pixel bits.
+const HALF_BLOCKS: [char; 4] = [' ', '▀', '▄', '█'];
+
+/// How many pixels each cell of a [`Canvas`] is split into.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub enum Resolution {
+    /// Each cell is 2 pixels wide and 4 pixels tall, drawn with braille patterns.
+    #[default]
+    Braille,
+    /// Each cell is 1 pixel wide and 2 pixels tall, drawn with half-block characters. Coarser than
+    /// [`Resolution::Braille`], but the pixels are square on most fonts and have no gaps between them.
+    HalfBlock,
+}
+
+impl Resolution {
+    /// Returns the width and height of a cell in pixels.
+    #[must_use]
+    pub const fn cell_dimensions(self) -> (usize, usize) {
+        match self {
+            Self::Braille => (2, 4),
+            Self::HalfBlock => (1, 2),
+        }
+    }
+}
+
+/// The pixels of a single cell of a [`Canvas`], along with the cell's colour.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Dots {
+    /// Which pixels of the cell are set. The meaning of each bit depends on the [`Resolution`].
+    pub bits: u8,
+    /// The foreground colour of the cell. If it's `None`, the colour is taken from the [`Canvas`]'s
+    /// style.
+    pub colour: Option<Colour>,
+}
+
+impl Dots {
+    /// A cell without any pixels set.
+    pub const EMPTY: Self = Self { bits: 0, colour: None };
+}
+
+/// A grid of pixels that can be drawn on, for plotting graphs and drawing shapes.
+///
+/// Each cell of the terminal is split into several pixels according to the canvas's [`Resolution`].
+/// The pixels are stored in a caller-provided slice of [`Dots`], one per cell, so the canvas never
+/// allocates. Pixels that are drawn outside of the canvas are ignored.
+///
+/// A terminal cell can only show one foreground colour, so a cell takes on the [`Canvas::pen`]
+/// colour of the last pixel drawn in it.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::Colour;
+/// use tuit::terminal::{ConstantSize, Rectangle};
+/// use tuit::widgets::builtins::{Canvas, Dots};
+///
+/// let mut terminal: ConstantSize<10, 3> = ConstantSize::new();
+/// let mut cells = [Dots::EMPTY; 10 * 3];
+/// let mut canvas = Canvas::new(&mut cells, 10);
+///
+/// // The canvas is 20x12 pixels.
+/// assert_eq!(canvas.pixel_dimensions(), (20, 12));
+///
+/// canvas.line((0, 0), (19, 11));
+/// canvas.pen = Some(Colour::Rgb24(255, 0, 0));
+/// canvas.fill(Rectangle::new((18, 0), (20, 4)));
+///
+/// canvas.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// // The line starts at the top-left dot of the first cell...
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, '⠑');
+/// // ...and the top-right cell is completely filled in red.
+/// assert_eq!(terminal.cell(9, 0).unwrap().character, '⣿');
+/// assert_eq!(terminal.cell(9, 0).unwrap().style.fg_colour, Some(Colour::Rgb24(255, 0, 0)));
+/// ```
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct Canvas<'a> {
+    /// The pixels of each cell, row by row.
+    cells: &'a mut [Dots],
+    /// The width of the canvas in cells.
+    width: usize,
+    /// How many pixels each cell is split into.
+    pub resolution: Resolution,
+    /// The colour that pixels are drawn with.
+    pub pen: Option<Colour>,
+    /// The style of the canvas. Its foreground colour is used for cells without a colour of their own.
+    pub style: Style,
+}
+
+impl<'a> Canvas<'a> {
+    /// Create a new [`Canvas`] that is `width` cells wide and stores its pixels in `cells`. It's as
+    /// many rows tall as `cells` can fill.
+    #[must_use]
+    pub const fn new(cells: &'a mut [Dots], width: usize) -> Self {
+        Self {
+            cells,
+            width,
+            resolution: Resolution::Braille,
+            pen: None,
+            style: Style::new(),
+        }
+    }
+
+    /// Set the [`Resolution`] of the [`Canvas`]. The pixels that have already been drawn are not converted.
+    #[must_use]
+    pub const fn with_resolution(mut self, resolution: Resolution) -> Self {
+        self.resolution = resolution;
+
+        self
+    }
+
+    /// Set the style of the [`Canvas`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Returns the width and height of the canvas in cells.
+    #[must_use]
+    pub const fn dimensions(&self) -> (usize, usize) {
+        match self.cells.len().checked_div(self.width) {
+            Some(height) => (self.width, height),
+            None => (0, 0),
+        }
+    }
+
+    /// Returns the width and height of the canvas in pixels.
+    #[must_use]
+    pub const fn pixel_dimensions(&self) -> (usize, usize) {
+        let (width, height) = self.dimensions();
+        let (cell_width, cell_height) = self.resolution.cell_dimensions();
+
+        (width * cell_width, height * cell_height)
+    }
+
+    /// Erases every pixel.
+    pub fn clear(&mut self) {
+        self.cells.fill(Dots::EMPTY);
+    }
+
+    /// Returns whether the pixel at the given coordinates is set. Pixels outside of the canvas are never set.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.locate(x, y).is_some_and(|(index, bit)| self.cells[index].bits & bit != 0)
+    }
+
+    /// Sets a single pixel.
+    pub fn point(&mut self, x: usize, y: usize) {
+        if let Some((index, bit)) = self.locate(x, y) {
+            self.cells[index].bits |= bit;
+            self.cells[index].colour = self.pen;
+        }
+    }
+
+    /// Draws a straight line between two pixels, inclusive, using Bresenham's algorithm.
+    pub fn line(&mut self, start: (usize, usize), end: (usize, usize)) {
+        let Some(((x0, y0), (x1, y1))) = self.clip(start, end) else {
+            return;
+        };
+
+        let (x0, y0, x1, y1) = (signed(x0), signed(y0), signed(x1), signed(y1));
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (step_x, step_y) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+        let (mut x, mut y, mut error) = (x0, y0, dx + dy);
+
+        loop {
+            self.plot(x, y);
+
+            if x == x1 && y == y1 {
+                break;
+            }
+
+            let doubled = error * 2;
+
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Draws the outline of a rectangle, in pixel coordinates.
+    pub fn rectangle(&mut self, rectangle: Rectangle) {
+        if rectangle.width() == 0 || rectangle.height() == 0 {
+            return;
+        }
+
+        let (left, top) = rectangle.left_top();
+        let (right, bottom) = (rectangle.right() - 1, rectangle.bottom() - 1);
+
+        self.line((left, top), (right, top));
+        self.line((left, bottom), (right, bottom));
+        self.line((left, top), (left, bottom));
+        self.line((right, top), (right, bottom));
+    }
+
+    /// Fills in every pixel of a rectangle, in pixel coordinates.
+    pub fn fill(&mut self, rectangle: Rectangle) {
+        let (width, height) = self.pixel_dimensions();
+
+        for y in rectangle.top()..rectangle.bottom().min(height) {
+            for x in rectangle.left()..rectangle.right().min(width) {
+                self.point(x, y);
+            }
+        }
+    }
+
+    /// Draws the outline of a circle around a centre pixel, using the midpoint circle algorithm.
+    ///
+    /// Circles that are bigger than the whole canvas are drawn by checking each pixel of the canvas
+    /// instead, so that only the part of the outline that's visible is ever visited.
+    pub fn circle(&mut self, centre: (usize, usize), radius: usize) {
+        let (width, height) = self.pixel_dimensions();
+
+        if radius > width.saturating_mul(height) {
+            return self.huge_circle(centre, radius);
+        }
+
+        let (centre_x, centre_y) = (signed(centre.0), signed(centre.1));
+        let (mut x, mut y) = (signed(radius), 0);
+        let mut error = 1 - x;
+
+        while x >= y {
+            for (offset_x, offset_y) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+                self.plot(centre_x + offset_x, centre_y + offset_y);
+            }
+
+            y += 1;
+
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    /// Sets every pixel of the canvas that's less than half of a pixel away from the outline of a circle.
+    fn huge_circle(&mut self, (centre_x, centre_y): (usize, usize), radius: usize) {
+        let (width, height) = self.pixel_dimensions();
+        let radius = radius as u128;
+        // `(r - 0.5)² <= d² < (r + 0.5)²` works out to `r² - r < d² <= r² + r` for whole numbers.
+        let (inner, outer) = ((false, radius * radius - radius), wide_add(radius * radius, radius));
+
+        for y in 0..height {
+            for x in 0..width {
+                let (dx, dy) = (x.abs_diff(centre_x) as u128, y.abs_diff(centre_y) as u128);
+                let distance = wide_add(dx * dx, dy * dy);
+
+                if inner < distance && distance <= outer {
+                    self.point(x, y);
+                }
+            }
+        }
+    }
+
+    /// Clips the line between two pixels to the canvas using the Liang-Barsky algorithm, so that drawing
+    /// it never steps through pixels that are far away from the canvas. Returns `None` if the line
+    /// misses the canvas entirely.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn clip(&self, start: (usize, usize), end: (usize, usize)) -> Option<((usize, usize), (usize, usize))> {
+        let (width, height) = self.pixel_dimensions();
+        let (right, bottom) = (width.checked_sub(1)?, height.checked_sub(1)?);
+        let (x0, y0) = (start.0 as f64, start.1 as f64);
+        let (dx, dy) = (end.0 as f64 - x0, end.1 as f64 - y0);
+        // How far along the line it enters and exits the canvas.
+        let (mut enter, mut exit) = (0.0_f64, 1.0_f64);
+
+        // Each edge of the canvas, as `p * t <= q` for the points `t` of the way along the line.
+        for (p, q) in [(-dx, x0), (dx, right as f64 - x0), (-dy, y0), (dy, bottom as f64 - y0)] {
+            if p < 0.0 {
+                enter = enter.max(q / p);
+            } else if p > 0.0 {
+                exit = exit.min(q / p);
+            } else if q < 0.0 {
+                return None;
+            }
+        }
+
+        if enter > exit {
+            return None;
+        }
+
+        // The casts saturate, and the results are clamped in case of rounding errors.
+        let point = |t: f64| (((x0 + t * dx).round() as usize).min(right), ((y0 + t * dy).round() as usize).min(bottom));
+
+        Some((point(enter), point(exit)))
+    }
+
+    /// Sets a pixel using signed coordinates, so that shapes can hang off of the top and left edges.
+    fn plot(&mut self, x: isize, y: isize) {
+        if let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) {
+            self.point(x, y);
+        }
+    }
+
+    /// Returns the index of the cell containing a pixel, and the pixel's bit within the cell.
+    const fn locate(&self, x: usize, y: usize) -> Option<(usize, u8)> {
+        let (width, height) = self.pixel_dimensions();
+
+        if x >= width || y >= height {
+            return None;
+        }
+
+        let (cell_width, cell_height) = self.resolution.cell_dimensions();
+        let (dot_x, dot_y) = (x % cell_width, y % cell_height);
+        let index = (y / cell_height) * self.width + x / cell_width;
+
+        let bit = match self.resolution {
+            Resolution::Braille => BRAILLE_DOTS[dot_y][dot_x],
+            Resolution::HalfBlock => 1 << dot_y,
+        };
+
+        Some((index, bit))
+    }
+
+    /// Returns the character that shows the given pixels.
+    fn glyph(&self, bits: u8) -> char {
+        match self.resolution {
+            // Empty cells are drawn as spaces rather than blank braille patterns, which some fonts draw as dots.
+            Resolution::Braille if bits == 0 => ' ',
+            Resolution::Braille => char::from_u32(0x2800 + u32::from(bits)).unwrap_or(' '),
+            Resolution::HalfBlock => HALF_BLOCKS[usize::from(bits & 0b11)],
+        }
+    }
+}
+
+/// Adds two [`u128`]s without losing the carry, since squared distances between pixels can need one more
+/// bit. The carry comes first so that the results compare in the right order.
+const fn wide_add(left: u128, right: u128) -> (bool, u128) {
+    let (low, carry) = left.overflowing_add(right);
+
+    (carry, low)
+}
+
+/// Converts a pixel coordinate so that it can be offset in any direction. Coordinates that are too
+/// large to convert are far outside of any canvas anyway.
+fn signed(coordinate: usize) -> isize {
+    isize::try_from(coordinate).unwrap_or(isize::MAX / 2)
+}
+
+impl Widget for Canvas<'_> {
+    fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let (width, height) = self.dimensions();
+        let (terminal_width, terminal_height) = terminal.dimensions();
+
+        for y in 0..height.min(terminal_height) {
+            for x in 0..width.min(terminal_width) {
+                let dots = self.cells[y * width + x];
+                let cell = terminal.cell_mut(x, y).ok_or_else(|| Error::oob_with((x, y)))?;
+                let style = dots.colour.map_or(self.style, |colour| self.style.fg(colour));
+
+                cell.character = self.glyph(dots.bits);
+                cell.style = style.inherits(cell.style);
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for Canvas<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let (width, height) = self.dimensions();
+
+        Ok(Rectangle::of_size((width.min(rect.width()), height.min(rect.height()))))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        let (width, height) = self.dimensions();
+
+        width >= rectangle.width() && height >= rectangle.height()
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::{Canvas, Dots};
+    use crate::terminal::Rectangle;
+
+    #[test]
+    fn far_away_shapes() {
+        let mut cells = [Dots::EMPTY; 4 * 2];
+        let mut canvas = Canvas::new(&mut cells, 4);
+
+        // These would take forever if every pixel along the way was visited.
+        canvas.line((0, 0), (usize::MAX, 0));
+        canvas.line((usize::MAX, usize::MAX), (usize::MAX - 1, 0));
+        canvas.circle((0, 0), usize::MAX);
+        canvas.circle((usize::MAX, usize::MAX), 3);
+        canvas.circle((usize::MAX, 7), usize::MAX - 7);
+        canvas.fill(Rectangle::new((6, 6), (usize::MAX, usize::MAX)));
+
+        assert!((0..8).all(|x| canvas.get(x, 0)));
+        assert!((1..7).all(|x| !canvas.get(x, 1)));
+        assert!(canvas.get(7, 7));
+        // The huge circle's outline is the column that's exactly its radius away from its centre.
+        assert!((2..8).all(|y| canvas.get(7, y)));
+        assert!((2..6).all(|y| !canvas.get(6, y)));
+    }
+
+    #[test]
+    fn clipped_lines_keep_their_slope() {
+        let mut cells = [Dots::EMPTY; 4 * 2];
+        let mut canvas = Canvas::new(&mut cells, 4);
+
+        // A diagonal line that starts well outside of the 8x8 canvas.
+        canvas.line((1000, 1000), (0, 0));
+
+        assert!((0..8).all(|i| canvas.get(i, i)));
+        assert!(!canvas.get(1, 0));
+    }
+
+    #[test]
+    fn circles_that_cross_the_canvas() {
+        let mut cells = [Dots::EMPTY; 4 * 2];
+        let mut canvas = Canvas::new(&mut cells, 4);
+
+        canvas.circle((3, 3), 3);
+        canvas.circle((100, 3), 97);
+
+        assert!(canvas.get(0, 3));
+        assert!(canvas.get(6, 3));
+        assert!(canvas.get(3, 0));
+    }
+}
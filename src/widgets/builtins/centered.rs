@@ -6,6 +6,7 @@ use crate::widgets::BoundingBox;
 /// A widget that centers its child widget within its bounding box.
 ///
 /// Child widgets need to implement [`BoundingBox`].
+#[allow(clippy::struct_field_names)]
 pub struct Centered<T> {
     child: T,
     centered_x: bool,
@@ -39,7 +40,7 @@ impl<T: BoundingBox> Widget for Centered<T> {
             new_height: bounding_box.bottom(),
         })?;
 
-        self.child.update(update_info, view)
+        self.child.update(update_info.mouse_relative_to(bounding_box), view)
     }
 
     fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
@@ -51,6 +52,18 @@ impl<T: BoundingBox> Widget for Centered<T> {
 
         self.child.draw(view)
     }
+
+    fn focusable(&self) -> usize {
+        self.child.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.child.set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.child.focused()
+    }
 }
 
 impl<T: BoundingBox> BoundingBox for Centered<T> {
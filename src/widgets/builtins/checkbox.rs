@@ -1,6 +1,6 @@
 use crate::prelude::{Terminal, TerminalConst, WithLayout};
 use crate::style::Style;
-use crate::terminal::{KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
+use crate::terminal::{Key, KeyState, MouseButton, Rectangle, UpdateInfo, UpdateResult};
 use crate::widgets::builtins::Text;
 use crate::widgets::{BoundingBox, Widget};
 
@@ -64,22 +64,15 @@ impl Widget for Checkbox<'_> {
         log::trace!("Checkbox update: {:?}", update_info);
 
         match update_info {
-            UpdateInfo::CellClicked(x, y, MouseButton::Primary) => {
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) if self.bounding_box_in(&terminal)?.contains((x, y)) => {
                 #[cfg(feature = "debug")]
-                {
-                    log::trace!("Checkbox saw click at ({x}, {y})");
-                    let bb = self.bounding_box_in(&terminal)?;
-                    log::trace!("Checkbox bounding box: {bb:?}");
-                }
+                log::trace!("Checkbox saw click at ({x}, {y})");
 
+                self.checked = !self.checked;
 
-                if self.bounding_box_in(&terminal)?.contains((x, y)) {
-                    self.checked = !self.checked;
-
-                    return Ok(UpdateResult::Interacted)
-                }
+                return Ok(UpdateResult::Interacted)
             }
-            UpdateInfo::KeyboardInput(0x28, KeyState::KeyDown) => {
+            UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown) => {
                 self.checked = !self.checked;
 
                 return Ok(UpdateResult::Interacted)
@@ -102,6 +95,10 @@ impl Widget for Checkbox<'_> {
 
         checkbox.draw(terminal)
     }
+
+    fn focusable(&self) -> usize {
+        1
+    }
 }
 
 impl BoundingBox for Checkbox<'_> {
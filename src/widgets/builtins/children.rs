@@ -0,0 +1,224 @@
+use crate::prelude::{Terminal, TerminalConst};
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::route_focus;
+use crate::widgets::{BoundingBox, Widget};
+use crate::Error;
+
+/// A fixed group of [`BoundingBox`] widgets that a layout can address by index, like the
+/// [`Flex`](super::Flex) layout.
+///
+/// [`Widget`]s can't be turned into trait objects, so this trait stands in for a slice of
+/// differently-typed widgets without needing to allocate. It's implemented for tuples of up to
+/// eight widgets, and for arrays of widgets of the same type.
+///
+/// Methods that are given an index without a child return [`Error::OutOfBoundsIndex`], or do nothing.
+pub trait Children {
+    /// A buffer with room for one [`Rectangle`] per child, so that layouts can solve where all of
+    /// their children go at once.
+    type Rects: AsRef<[Rectangle]> + AsMut<[Rectangle]>;
+
+    /// Returns the number of children.
+    fn count(&self) -> usize;
+
+    /// Returns a buffer of empty [`Rectangle`]s, one for each child.
+    fn rects(&self) -> Self::Rects;
+
+    /// Calls [`BoundingBox::bounding_box`] on a child.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if there is no child at `index`, or if the child returns one.
+    fn bounding_box(&self, index: usize, rect: Rectangle) -> crate::Result<Rectangle>;
+
+    /// Calls [`BoundingBox::completely_covers`] on a child.
+    fn completely_covers(&self, index: usize, rect: Rectangle) -> bool;
+
+    /// Calls [`Widget::update`] on a child.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if there is no child at `index`, or if the child returns one.
+    fn update(&mut self, index: usize, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult>;
+
+    /// Calls [`Widget::draw`] on a child.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if there is no child at `index`, or if the child returns one.
+    fn draw(&self, index: usize, terminal: impl Terminal) -> crate::Result<UpdateResult>;
+
+    /// Calls [`Widget::focusable`] on a child.
+    fn focusable(&self, index: usize) -> usize;
+
+    /// Calls [`Widget::set_focus`] on a child.
+    fn set_focus(&mut self, index: usize, focus: Option<usize>);
+}
+
+impl<T: BoundingBox, const N: usize> Children for [T; N] {
+    type Rects = [Rectangle; N];
+
+    fn count(&self) -> usize {
+        N
+    }
+
+    fn rects(&self) -> Self::Rects {
+        [Rectangle::default(); N]
+    }
+
+    fn bounding_box(&self, index: usize, rect: Rectangle) -> crate::Result<Rectangle> {
+        self.get(index).ok_or_else(|| Error::oobi(index))?.bounding_box(rect)
+    }
+
+    fn completely_covers(&self, index: usize, rect: Rectangle) -> bool {
+        self.get(index).is_some_and(|child| child.completely_covers(rect))
+    }
+
+    fn update(&mut self, index: usize, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        self.get_mut(index).ok_or_else(|| Error::oobi(index))?.update(update_info, terminal)
+    }
+
+    fn draw(&self, index: usize, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        self.get(index).ok_or_else(|| Error::oobi(index))?.draw(terminal)
+    }
+
+    fn focusable(&self, index: usize) -> usize {
+        self.get(index).map_or(0, Widget::focusable)
+    }
+
+    fn set_focus(&mut self, index: usize, focus: Option<usize>) {
+        if let Some(child) = self.get_mut(index) {
+            child.set_focus(focus);
+        }
+    }
+}
+
+/// Implements [`Children`] for a tuple, given each of its indices and type parameters.
+macro_rules! impl_children_for_tuple {
+    ($count:literal; $($index:tt $child:ident),+) => {
+        impl<$($child: BoundingBox),+> Children for ($($child,)+) {
+            type Rects = [Rectangle; $count];
+
+            fn count(&self) -> usize {
+                $count
+            }
+
+            fn rects(&self) -> Self::Rects {
+                [Rectangle::default(); $count]
+            }
+
+            fn bounding_box(&self, index: usize, rect: Rectangle) -> crate::Result<Rectangle> {
+                match index {
+                    $($index => self.$index.bounding_box(rect),)+
+                    _ => Err(Error::oobi(index)),
+                }
+            }
+
+            fn completely_covers(&self, index: usize, rect: Rectangle) -> bool {
+                match index {
+                    $($index => self.$index.completely_covers(rect),)+
+                    _ => false,
+                }
+            }
+
+            fn update(&mut self, index: usize, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+                match index {
+                    $($index => self.$index.update(update_info, terminal),)+
+                    _ => Err(Error::oobi(index)),
+                }
+            }
+
+            fn draw(&self, index: usize, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+                match index {
+                    $($index => self.$index.draw(terminal),)+
+                    _ => Err(Error::oobi(index)),
+                }
+            }
+
+            fn focusable(&self, index: usize) -> usize {
+                match index {
+                    $($index => self.$index.focusable(),)+
+                    _ => 0,
+                }
+            }
+
+            fn set_focus(&mut self, index: usize, focus: Option<usize>) {
+                match index {
+                    $($index => self.$index.set_focus(focus),)+
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+impl_children_for_tuple!(1; 0 A);
+impl_children_for_tuple!(2; 0 A, 1 B);
+impl_children_for_tuple!(3; 0 A, 1 B, 2 C);
+impl_children_for_tuple!(4; 0 A, 1 B, 2 C, 3 D);
+impl_children_for_tuple!(5; 0 A, 1 B, 2 C, 3 D, 4 E);
+impl_children_for_tuple!(6; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_children_for_tuple!(7; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_children_for_tuple!(8; 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+/// Returns the total number of focusable widgets inside of all the children.
+pub(crate) fn total_focusable(children: &impl Children) -> usize {
+    (0..children.count()).map(|index| children.focusable(index)).sum()
+}
+
+/// Returns the focus of the child at `index`, based on the focus of the layout holding the children.
+pub(crate) fn child_focus(children: &impl Children, focus: Option<usize>, index: usize) -> Option<usize> {
+    let first: usize = (0..index).map(|child| children.focusable(child)).sum();
+
+    focus
+        .and_then(|focus| focus.checked_sub(first))
+        .filter(|&focus| focus < children.focusable(index))
+}
+
+/// Updates each child in the rectangle that it's laid out in, skipping the empty ones. Mouse events
+/// only go to the children that they happened over, relative to their rectangles.
+pub(crate) fn update_children(children: &mut impl Children, rects: &[Rectangle], focus: Option<usize>, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+    let mut result = UpdateResult::NoEvent;
+
+    for (index, &rect) in rects.iter().enumerate() {
+        if rect.area() == 0 {
+            continue;
+        }
+
+        let child_update_info = match update_info.mouse_position() {
+            Some(position) if !rect.contains(position) => UpdateInfo::NoInfo,
+            _ => route_focus(update_info, focus, child_focus(children, focus, index)).mouse_relative_to(rect),
+        };
+
+        let view = terminal.view(rect).ok_or_else(|| Error::rescale_to(rect))?;
+
+        result = result.max(children.update(index, child_update_info, view)?);
+    }
+
+    Ok(result)
+}
+
+/// Draws each child in the rectangle that it's laid out in, skipping the empty ones.
+pub(crate) fn draw_children(children: &impl Children, rects: &[Rectangle], mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+    let mut result = UpdateResult::NoEvent;
+
+    for (index, &rect) in rects.iter().enumerate() {
+        if rect.area() == 0 {
+            continue;
+        }
+
+        let view = terminal.view_mut(rect).ok_or_else(|| Error::rescale_to(rect))?;
+
+        result = result.max(children.draw(index, view)?);
+    }
+
+    Ok(result)
+}
+
+/// Passes the focus of the layout holding the children on to each child.
+pub(crate) fn set_children_focus(children: &mut impl Children, focus: Option<usize>) {
+    for index in 0..children.count() {
+        let focus = child_focus(children, focus, index);
+
+        children.set_focus(index, focus);
+    }
+}
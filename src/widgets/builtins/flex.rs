@@ -0,0 +1,330 @@
+use crate::prelude::{Terminal, TerminalConst};
+use crate::terminal::view_split::WeightedSplit;
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::children::{draw_children, set_children_focus, total_focusable, update_children};
+use crate::widgets::builtins::Children;
+use crate::widgets::{Axis, BoundingBox, Widget};
+use crate::Error;
+
+/// How much space a child of a [`Flex`] layout takes up along the main axis.
+///
+/// Percentages and ratios are of the space left after the gaps between children.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Constraint {
+    /// Exactly this many cells.
+    Length(usize),
+    /// This percentage of the space.
+    Percentage(usize),
+    /// This fraction of the space, as a numerator and a denominator.
+    Ratio(usize, usize),
+    /// The child's own size, but at least this many cells.
+    Min(usize),
+    /// The child's own size, but at most this many cells.
+    Max(usize),
+    /// A share of the space that the other children leave over, proportional to this weight.
+    Fill(usize),
+}
+
+/// Where the children of a [`Flex`] layout are placed along the main axis when they don't fill it.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub enum MainAlignment {
+    /// Children are packed towards the start.
+    #[default]
+    Start,
+    /// Children are packed in the middle.
+    Center,
+    /// Children are packed towards the end.
+    End,
+    /// The leftover space is spread evenly between the children.
+    SpaceBetween,
+}
+
+/// Where each child of a [`Flex`] layout is placed along the cross axis.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub enum CrossAlignment {
+    /// Children take up the whole cross axis.
+    #[default]
+    Stretch,
+    /// Children are placed at the start of the cross axis, at their own size.
+    Start,
+    /// Children are placed in the middle of the cross axis, at their own size.
+    Center,
+    /// Children are placed at the end of the cross axis, at their own size.
+    End,
+}
+
+/// Lays out any number of children in a row or a column, sizing each according to a [`Constraint`].
+///
+/// The children are a tuple or an array; see [`Children`]. Children without a matching constraint
+/// are sized as if they had [`Constraint::Min(0)`](Constraint::Min), which is their own size.
+/// Sizes are solved in a single pass: fixed-size constraints are given their space first, and
+/// whatever's left is split between the [`Constraint::Fill`] children. If the children don't fit,
+/// the last ones are cut short.
+///
+/// A [`Flex`] always takes up all of the space that it's given.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::{Constraint, Flex, Text};
+///
+/// let mut terminal: ConstantSize<20, 10> = ConstantSize::new();
+///
+/// let constraints = [Constraint::Length(1), Constraint::Fill(1), Constraint::Percentage(20)];
+/// let dashboard = Flex::column((Text::new("CPU"), Text::new("Memory"), Text::new("Disk")), &constraints).gap(1);
+///
+/// dashboard.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// // The percentage is of the 8 rows that are left after the gaps, so the last child gets one row.
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, 'C');
+/// assert_eq!(terminal.cell(0, 2).unwrap().character, 'M');
+/// assert_eq!(terminal.cell(0, 9).unwrap().character, 'D');
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Flex<'a, C> {
+    /// The children of the layout.
+    children: C,
+    /// The constraint of each child, in order.
+    pub constraints: &'a [Constraint],
+    /// The axis that the children are laid out along.
+    pub axis: Axis,
+    /// The number of cells between each pair of children.
+    pub gap: usize,
+    /// Where the children are placed along the main axis when they don't fill it.
+    pub main_alignment: MainAlignment,
+    /// Where each child is placed along the cross axis.
+    pub cross_alignment: CrossAlignment,
+    focus: Option<usize>,
+}
+
+impl<'a, C: Children> Flex<'a, C> {
+    /// Create a new [`Flex`] that lays its children out left-to-right.
+    #[must_use]
+    pub const fn row(children: C, constraints: &'a [Constraint]) -> Self {
+        Self {
+            children,
+            constraints,
+            axis: Axis::Horizontal,
+            gap: 0,
+            main_alignment: MainAlignment::Start,
+            cross_alignment: CrossAlignment::Stretch,
+            focus: None,
+        }
+    }
+
+    /// Create a new [`Flex`] that lays its children out top-to-bottom.
+    #[must_use]
+    pub const fn column(children: C, constraints: &'a [Constraint]) -> Self {
+        let mut flex = Self::row(children, constraints);
+        flex.axis = Axis::Vertical;
+
+        flex
+    }
+
+    /// Set the number of cells between each pair of children.
+    #[must_use]
+    pub const fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+
+        self
+    }
+
+    /// Set where the children are placed along the main axis when they don't fill it.
+    #[must_use]
+    pub const fn main_aligned(mut self, alignment: MainAlignment) -> Self {
+        self.main_alignment = alignment;
+
+        self
+    }
+
+    /// Set where each child is placed along the cross axis.
+    #[must_use]
+    pub const fn cross_aligned(mut self, alignment: CrossAlignment) -> Self {
+        self.cross_alignment = alignment;
+
+        self
+    }
+
+    /// Get a reference to the children.
+    pub const fn inner(&self) -> &C {
+        &self.children
+    }
+
+    /// Get a mutable reference to the children.
+    pub const fn inner_mut(&mut self) -> &mut C {
+        &mut self.children
+    }
+
+    /// Consume the [`Flex`] and return its children.
+    pub fn into_inner(self) -> C {
+        self.children
+    }
+
+    /// Returns the size of a rectangle along the main axis and the cross axis.
+    const fn lengths(&self, rect: Rectangle) -> (usize, usize) {
+        match self.axis {
+            Axis::Horizontal => (rect.width(), rect.height()),
+            Axis::Vertical => (rect.height(), rect.width()),
+        }
+    }
+
+    /// Returns a rectangle inside of `bounds`, given its offset and size along each axis.
+    const fn rect_in(&self, bounds: Rectangle, (main_offset, cross_offset): (usize, usize), (main, cross): (usize, usize)) -> Rectangle {
+        let (left, top) = bounds.left_top();
+
+        match self.axis {
+            Axis::Horizontal => Rectangle::new((left + main_offset, top + cross_offset), (left + main_offset + main, top + cross_offset + cross)),
+            Axis::Vertical => Rectangle::new((left + cross_offset, top + main_offset), (left + cross_offset + cross, top + main_offset + main)),
+        }
+    }
+
+    /// Returns the size that a child's constraint gives it out of `space`, and its weight if it's a
+    /// [`Constraint::Fill`] instead.
+    fn base_size(&self, index: usize, space: usize, bounds: Rectangle) -> crate::Result<(usize, usize)> {
+        let own_size = || Ok::<_, Error>(self.lengths(self.children.bounding_box(index, bounds)?).0);
+
+        Ok(match self.constraints.get(index).copied().unwrap_or(Constraint::Min(0)) {
+            Constraint::Length(length) => (length, 0),
+            Constraint::Percentage(percentage) => (space * percentage / 100, 0),
+            Constraint::Ratio(numerator, denominator) => ((space * numerator).checked_div(denominator).unwrap_or(0), 0),
+            Constraint::Min(min) => (own_size()?.max(min), 0),
+            Constraint::Max(max) => (own_size()?.min(max), 0),
+            Constraint::Fill(weight) => (0, weight),
+        })
+    }
+
+    /// Returns the rectangle that a child is laid out in, inside of `bounds`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if there is no child at `index`, or if a child can't work out its bounding box.
+    pub fn child_rect(&self, index: usize, bounds: Rectangle) -> crate::Result<Rectangle> {
+        self.child_rects(bounds)?.as_ref().get(index).copied().ok_or_else(|| Error::oobi(index))
+    }
+
+    /// Returns the rectangles that all of the children are laid out in, inside of `bounds`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if a child can't work out its bounding box.
+    pub fn child_rects(&self, bounds: Rectangle) -> crate::Result<C::Rects> {
+        let mut rects = self.children.rects();
+        let count = self.children.count();
+
+        let (available, cross_length) = self.lengths(bounds);
+        let space = available.saturating_sub(self.gap * count.saturating_sub(1));
+
+        let (mut fixed, mut weights) = (0, 0);
+
+        for child in 0..count {
+            let (size, weight) = self.base_size(child, space, bounds)?;
+            fixed += size;
+            weights += weight;
+        }
+
+        let leftover = space.saturating_sub(fixed);
+        let (fill_space, unused) = if weights > 0 { (leftover, 0) } else { (0, leftover) };
+        let mut fill = WeightedSplit::new(fill_space, weights);
+
+        let mut offset = match self.main_alignment {
+            MainAlignment::Start | MainAlignment::SpaceBetween => 0,
+            MainAlignment::Center => unused / 2,
+            MainAlignment::End => unused,
+        };
+
+        for (child, rect) in rects.as_mut().iter_mut().enumerate() {
+            let (size, weight) = self.base_size(child, space, bounds)?;
+            let size = size + fill.piece(weight);
+
+            let clamped_offset = offset.min(available);
+            let clamped_size = size.min(available - clamped_offset);
+
+            let cross = if self.cross_alignment == CrossAlignment::Stretch {
+                cross_length
+            } else {
+                let measure = self.rect_in(bounds, (clamped_offset, 0), (clamped_size, cross_length));
+                self.lengths(self.children.bounding_box(child, measure)?).1.min(cross_length)
+            };
+
+            let cross_offset = match self.cross_alignment {
+                CrossAlignment::Stretch | CrossAlignment::Start => 0,
+                CrossAlignment::Center => (cross_length - cross) / 2,
+                CrossAlignment::End => cross_length - cross,
+            };
+
+            *rect = self.rect_in(bounds, (clamped_offset, cross_offset), (clamped_size, cross));
+
+            let spacing = match self.main_alignment {
+                MainAlignment::SpaceBetween => {
+                    (unused * (child + 1)).checked_div(count - 1).unwrap_or(0) - (unused * child).checked_div(count - 1).unwrap_or(0)
+                }
+                _ => 0,
+            };
+
+            offset += size + self.gap + spacing;
+        }
+
+        Ok(rects)
+    }
+}
+
+impl<C: Children> Widget for Flex<'_, C> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let rects = self.child_rects(terminal.bounding_box())?;
+
+        update_children(&mut self.children, rects.as_ref(), self.focus, update_info, terminal)
+    }
+
+    fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let rects = self.child_rects(terminal.bounding_box())?;
+
+        draw_children(&self.children, rects.as_ref(), terminal)
+    }
+
+    fn focusable(&self) -> usize {
+        total_focusable(&self.children)
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.focus = focus.filter(|&index| index < self.focusable());
+
+        set_children_focus(&mut self.children, self.focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.focus
+    }
+}
+
+impl<C: Children> BoundingBox for Flex<'_, C> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(rect)
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        let count = self.children.count();
+        let (available, cross_length) = self.lengths(rectangle);
+
+        if self.gap > 0 && count > 1 {
+            return false;
+        }
+
+        let Ok(rects) = self.child_rects(rectangle) else {
+            return false;
+        };
+
+        let mut covered = 0;
+
+        for (index, &rect) in rects.as_ref().iter().enumerate() {
+            let (main, cross) = self.lengths(rect);
+
+            if cross != cross_length || !self.children.completely_covers(index, rect) {
+                return false;
+            }
+
+            covered += main;
+        }
+
+        covered == available
+    }
+}
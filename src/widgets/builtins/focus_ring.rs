@@ -0,0 +1,143 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::terminal::{Key, KeyState, Modifiers, Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::BoundingBox;
+
+/// Moves the keyboard focus between the focusable widgets inside of its child. Tab focuses the next
+/// widget and Shift+Tab focuses the previous one, wrapping around at either end.
+///
+/// Widgets are focused in the order that they are laid out in, and only the focused widget receives
+/// keyboard input. Everything other than Tab gets passed through to the child.
+///
+/// Because the [`FocusRing`] uses up Tab and Shift+Tab, widgets inside of it won't see them.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Key, KeyState, UpdateInfo};
+/// use tuit::widgets::builtins::checkbox::Checkbox;
+/// use tuit::widgets::builtins::FocusRing;
+///
+/// let terminal: ConstantSize<20, 5> = ConstantSize::new();
+/// let form = Checkbox::new("First").on_top_of(Checkbox::new("Second"));
+/// let mut form = FocusRing::new(form);
+///
+/// let enter = UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown);
+/// let tab = UpdateInfo::KeyboardInput(Key::Tab, KeyState::KeyDown);
+///
+/// // Only the focused checkbox gets toggled.
+/// form.update(enter, &terminal).expect("Should update successfully");
+/// assert!(form.inner().higher_widget.checked);
+/// assert!(!form.inner().lower_widget.checked);
+///
+/// form.update(tab, &terminal).expect("Should update successfully");
+/// assert_eq!(form.focused(), Some(1));
+///
+/// form.update(enter, &terminal).expect("Should update successfully");
+/// assert!(form.inner().higher_widget.checked);
+/// assert!(form.inner().lower_widget.checked);
+///
+/// // The focus wraps back around to the start.
+/// form.update(tab, &terminal).expect("Should update successfully");
+/// assert_eq!(form.focused(), Some(0));
+/// ```
+pub struct FocusRing<T> {
+    /// The child widget
+    child: T,
+}
+
+impl<T: Widget> FocusRing<T> {
+    /// Create a new [`FocusRing`] around a child widget, and focus the first focusable widget inside of it.
+    pub fn new(mut child: T) -> Self {
+        child.set_focus(Some(0));
+
+        Self { child }
+    }
+
+    /// Focuses the next focusable widget, wrapping around to the first one after the last.
+    pub fn focus_next(&mut self) {
+        let count = self.child.focusable();
+
+        if count == 0 {
+            return;
+        }
+
+        let next = self.child.focused().map_or(0, |focus| (focus + 1) % count);
+
+        self.child.set_focus(Some(next));
+    }
+
+    /// Focuses the previous focusable widget, wrapping around to the last one before the first.
+    pub fn focus_previous(&mut self) {
+        let count = self.child.focusable();
+
+        if count == 0 {
+            return;
+        }
+
+        let previous = self.child.focused().map_or(count - 1, |focus| (focus + count - 1) % count);
+
+        self.child.set_focus(Some(previous));
+    }
+}
+
+impl<T> FocusRing<T> {
+    /// Get a reference to the child widget
+    pub const fn inner(&self) -> &T {
+        &self.child
+    }
+
+    /// Get a mutable reference to the child widget
+    pub const fn inner_mut(&mut self) -> &mut T {
+        &mut self.child
+    }
+
+    /// Consume [`self`] and return the child widget.
+    pub fn into_inner(self) -> T {
+        self.child
+    }
+}
+
+impl<T: Widget> Widget for FocusRing<T> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        match update_info {
+            UpdateInfo::KeyboardInput(Key::Tab, KeyState::KeyDown | KeyState::KeyHeld) => {
+                self.focus_next();
+
+                Ok(UpdateResult::Interacted)
+            }
+            UpdateInfo::ModifiedKeyboardInput(Key::Tab, Modifiers::SHIFT, KeyState::KeyDown | KeyState::KeyHeld) => {
+                self.focus_previous();
+
+                Ok(UpdateResult::Interacted)
+            }
+            UpdateInfo::KeyboardInput(Key::Tab, KeyState::KeyUp)
+            | UpdateInfo::ModifiedKeyboardInput(Key::Tab, Modifiers::SHIFT, KeyState::KeyUp) => Ok(UpdateResult::NoEvent),
+            _ => self.child.update(update_info, terminal),
+        }
+    }
+
+    fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        self.child.draw(terminal)
+    }
+
+    fn focusable(&self) -> usize {
+        self.child.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.child.set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.child.focused()
+    }
+}
+
+impl<T: BoundingBox> BoundingBox for FocusRing<T> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        self.child.bounding_box(rect)
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        self.child.completely_covers(rectangle)
+    }
+}
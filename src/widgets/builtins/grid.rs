@@ -0,0 +1,351 @@
+use crate::prelude::{Terminal, TerminalConst};
+use crate::terminal::view_split::{length_along, WeightedSplit};
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::children::{draw_children, set_children_focus, total_focusable, update_children};
+use crate::widgets::builtins::Children;
+use crate::widgets::{Axis, BoundingBox, Widget};
+use crate::Error;
+
+/// The size of a row or a column of a [`Grid`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Track {
+    /// Exactly this many cells.
+    Fixed(usize),
+    /// A share of the space that the other tracks leave over, proportional to this weight.
+    Fraction(usize),
+    /// As large as the largest child that is placed in only this track.
+    Auto,
+}
+
+/// Where a child of a [`Grid`] is placed, in tracks.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Placement {
+    /// The first column that the child is in.
+    pub column: usize,
+    /// The first row that the child is in.
+    pub row: usize,
+    /// The number of columns that the child spans.
+    pub column_span: usize,
+    /// The number of rows that the child spans.
+    pub row_span: usize,
+}
+
+impl Placement {
+    /// Place a child in a single cell of the grid.
+    #[must_use]
+    pub const fn at(column: usize, row: usize) -> Self {
+        Self {
+            column,
+            row,
+            column_span: 1,
+            row_span: 1,
+        }
+    }
+
+    /// Make the child span several columns and rows, starting from where it's placed.
+    #[must_use]
+    pub const fn span(mut self, columns: usize, rows: usize) -> Self {
+        self.column_span = columns;
+        self.row_span = rows;
+
+        self
+    }
+
+    /// Returns the first track and the number of tracks that the child is in along an axis.
+    const fn along(self, axis: Axis) -> (usize, usize) {
+        match axis {
+            Axis::Horizontal => (self.column, self.column_span),
+            Axis::Vertical => (self.row, self.row_span),
+        }
+    }
+}
+
+/// Lays out any number of children in a two-dimensional grid of rows and columns.
+///
+/// The children are a tuple or an array; see [`Children`]. Each child is placed according to the
+/// [`Placement`] at the same index, and can span several tracks. Children without a placement fill
+/// the grid left-to-right, top-to-bottom, one cell each, as if they weren't given any placements.
+/// Each child is given a [`View`](crate::terminal::View) of all the cells that it spans.
+///
+/// Mouse events are only passed to the children under the pointer, relative to their own views.
+///
+/// A [`Grid`] always takes up all of the space that it's given.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, MouseButton, UpdateInfo};
+/// use tuit::widgets::builtins::checkbox::Checkbox;
+/// use tuit::widgets::builtins::{Grid, Placement, Text, Track};
+///
+/// let mut terminal: ConstantSize<20, 4> = ConstantSize::new();
+///
+/// let columns = [Track::Fixed(6), Track::Fraction(1)];
+/// let rows = [Track::Auto, Track::Fraction(1)];
+/// // The title spans both columns.
+/// let placements = [Placement::at(0, 0).span(2, 1), Placement::at(0, 1), Placement::at(1, 1)];
+///
+/// let mut dashboard = Grid::new((Text::new("Sensors"), Text::new("Fan"), Checkbox::new("Auto")), &columns, &rows)
+///     .placed(&placements);
+///
+/// dashboard.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, 'S');
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, 'F');
+///
+/// // The click is passed to the checkbox, relative to the top-left of its cell.
+/// dashboard.update(UpdateInfo::CellClicked(7, 1, MouseButton::Primary), &terminal).unwrap();
+///
+/// assert!(dashboard.inner().2.checked);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Grid<'a, C> {
+    /// The children of the grid.
+    children: C,
+    /// The columns of the grid, left-to-right.
+    pub columns: &'a [Track],
+    /// The rows of the grid, top-to-bottom.
+    pub rows: &'a [Track],
+    /// Where each child is placed, in order.
+    pub placements: &'a [Placement],
+    /// The number of cells between each pair of columns.
+    pub column_gap: usize,
+    /// The number of cells between each pair of rows.
+    pub row_gap: usize,
+    focus: Option<usize>,
+}
+
+impl<'a, C: Children> Grid<'a, C> {
+    /// Create a new [`Grid`] with the given columns and rows, that fills them with its children in order.
+    #[must_use]
+    pub const fn new(children: C, columns: &'a [Track], rows: &'a [Track]) -> Self {
+        Self {
+            children,
+            columns,
+            rows,
+            placements: &[],
+            column_gap: 0,
+            row_gap: 0,
+            focus: None,
+        }
+    }
+
+    /// Set where each child is placed.
+    #[must_use]
+    pub const fn placed(mut self, placements: &'a [Placement]) -> Self {
+        self.placements = placements;
+
+        self
+    }
+
+    /// Set the number of cells between each pair of columns, and between each pair of rows.
+    #[must_use]
+    pub const fn gap(mut self, column_gap: usize, row_gap: usize) -> Self {
+        self.column_gap = column_gap;
+        self.row_gap = row_gap;
+
+        self
+    }
+
+    /// Get a reference to the children.
+    pub const fn inner(&self) -> &C {
+        &self.children
+    }
+
+    /// Get a mutable reference to the children.
+    pub const fn inner_mut(&mut self) -> &mut C {
+        &mut self.children
+    }
+
+    /// Consume the [`Grid`] and return its children.
+    pub fn into_inner(self) -> C {
+        self.children
+    }
+
+    /// Returns where a child is placed.
+    fn placement(&self, index: usize) -> Placement {
+        let columns = self.columns.len().max(1);
+
+        self.placements
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| Placement::at(index % columns, index / columns))
+    }
+
+    /// Returns the tracks and the gap between them along an axis.
+    const fn tracks(&self, axis: Axis) -> (&'a [Track], usize) {
+        match axis {
+            Axis::Horizontal => (self.columns, self.column_gap),
+            Axis::Vertical => (self.rows, self.row_gap),
+        }
+    }
+
+    /// Returns the rectangle that a child is laid out in, inside of `bounds`. Children that are
+    /// placed outside of the grid get an empty rectangle.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if there is no child at `index`, or if a child can't work out its bounding box.
+    pub fn child_rect(&self, index: usize, bounds: Rectangle) -> crate::Result<Rectangle> {
+        self.child_rects(bounds)?.as_ref().get(index).copied().ok_or_else(|| Error::oobi(index))
+    }
+
+    /// Returns the rectangles that all of the children are laid out in, inside of `bounds`. Children
+    /// that are placed outside of the grid get empty rectangles.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if a child can't work out its bounding box.
+    pub fn child_rects(&self, bounds: Rectangle) -> crate::Result<C::Rects> {
+        // Each child is measured at most once, and only if an `Auto` track needs its size.
+        let mut measured = self.children.rects();
+
+        for (index, rect) in measured.as_mut().iter_mut().enumerate() {
+            if self.sizes_track(index) {
+                *rect = self.children.bounding_box(index, bounds)?;
+            }
+        }
+
+        let mut rects = self.children.rects();
+
+        for axis in [Axis::Horizontal, Axis::Vertical] {
+            self.lay_out_along(axis, bounds, measured.as_ref(), rects.as_mut());
+        }
+
+        for rect in rects.as_mut() {
+            *rect = rect.at((bounds.left() + rect.left(), bounds.top() + rect.top()));
+        }
+
+        Ok(rects)
+    }
+
+    /// Returns whether a child is the only child in a [`Track::Auto`] track along either axis, which
+    /// means that it needs to be measured to size that track.
+    fn sizes_track(&self, index: usize) -> bool {
+        let placement = self.placement(index);
+
+        [Axis::Horizontal, Axis::Vertical].into_iter().any(|axis| {
+            let (tracks, _) = self.tracks(axis);
+            let (start, span) = placement.along(axis);
+
+            span == 1 && tracks.get(start) == Some(&Track::Auto)
+        })
+    }
+
+    /// Returns the size of a track, apart from the leftover space that a [`Track::Fraction`] gets.
+    /// An [`Track::Auto`] track is as large as the largest child that is only in that track, going
+    /// by the bounding boxes that the children were measured to have.
+    fn track_size(&self, axis: Axis, track: usize, kind: Track, measured: &[Rectangle]) -> usize {
+        match kind {
+            Track::Fixed(size) => size,
+            Track::Fraction(_) => 0,
+            Track::Auto => measured
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| self.placement(index).along(axis) == (track, 1))
+                .map(|(_, &rect)| length_along(rect, axis))
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    /// Lays the children out along one axis of `bounds`, by sweeping through the tracks once and
+    /// moving the edges of each child's rectangle to the tracks that it starts and ends in.
+    fn lay_out_along(&self, axis: Axis, bounds: Rectangle, measured: &[Rectangle], rects: &mut [Rectangle]) {
+        let (tracks, gap) = self.tracks(axis);
+        let available = length_along(bounds, axis);
+        let space = available.saturating_sub(gap * tracks.len().saturating_sub(1));
+
+        let fixed: usize = tracks.iter().enumerate().map(|(track, &kind)| self.track_size(axis, track, kind, measured)).sum();
+        let fractions: usize = tracks.iter().map(|&kind| if let Track::Fraction(weight) = kind { weight } else { 0 }).sum();
+        let mut leftover = WeightedSplit::new(space.saturating_sub(fixed), fractions);
+
+        // Children that start outside of the grid are left at its far edge.
+        for rect in rects.iter_mut() {
+            *rect = with_span(*rect, axis, (available, available));
+        }
+
+        let mut offset = 0;
+
+        for (track, &kind) in tracks.iter().enumerate() {
+            let size = match kind {
+                Track::Fraction(weight) => leftover.piece(weight),
+                _ => self.track_size(axis, track, kind, measured),
+            };
+
+            for (index, rect) in rects.iter_mut().enumerate() {
+                let (start, span) = self.placement(index).along(axis);
+
+                if (start..start.saturating_add(span)).contains(&track) {
+                    let from = if track == start { offset } else { span_of(*rect, axis).0 };
+
+                    *rect = with_span(*rect, axis, (from, offset + size));
+                }
+            }
+
+            offset += size + gap;
+        }
+
+        for rect in rects {
+            let (from, to) = span_of(*rect, axis);
+            let from = from.min(available);
+
+            *rect = with_span(*rect, axis, (from, to.clamp(from, available)));
+        }
+    }
+}
+
+impl<C: Children> Widget for Grid<'_, C> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let rects = self.child_rects(terminal.bounding_box())?;
+
+        update_children(&mut self.children, rects.as_ref(), self.focus, update_info, terminal)
+    }
+
+    fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let rects = self.child_rects(terminal.bounding_box())?;
+
+        draw_children(&self.children, rects.as_ref(), terminal)
+    }
+
+    fn focusable(&self) -> usize {
+        total_focusable(&self.children)
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.focus = focus.filter(|&index| index < self.focusable());
+
+        set_children_focus(&mut self.children, self.focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.focus
+    }
+}
+
+impl<C: Children> BoundingBox for Grid<'_, C> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(rect)
+    }
+
+    // Cells without children, and the gaps between tracks, are not drawn over,
+    // so the grid does not completely cover the [`Rectangle`].
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        false
+    }
+}
+
+/// Returns where a [`Rectangle`] starts and ends along an axis.
+const fn span_of(rect: Rectangle, axis: Axis) -> (usize, usize) {
+    match axis {
+        Axis::Horizontal => (rect.left(), rect.right()),
+        Axis::Vertical => (rect.top(), rect.bottom()),
+    }
+}
+
+/// Returns a [`Rectangle`] that starts and ends at the given offsets along an axis, and is otherwise the same.
+const fn with_span(rect: Rectangle, axis: Axis, (from, to): (usize, usize)) -> Rectangle {
+    match axis {
+        Axis::Horizontal => Rectangle::new((from, rect.top()), (to, rect.bottom())),
+        Axis::Vertical => Rectangle::new((rect.left(), from), (rect.right(), to)),
+    }
+}
@@ -0,0 +1,302 @@
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Key, KeyState, MouseButton, Rectangle, ScrollDirection, Terminal, TerminalConst, TerminalMut, UpdateInfo, UpdateResult};
+use crate::widgets::{BoundingBox, Widget};
+use crate::Error;
+
+/// A widget that displays a list of items, top-to-bottom, one of which can be selected.
+///
+/// The list scrolls to keep the selected item in view when there are more items than rows. The
+/// selected item's row is highlighted all the way across, and each item can be preceded by a
+/// marker that shows whether it's selected.
+///
+/// It reacts to these events:
+/// - Up and Down (or the scroll wheel) move the selection by one item, and Page Up and Page Down
+///   move it by a whole screen.
+/// - Home and End select the first and last item.
+/// - Enter or Space activates the selected item, see [`List::activated`].
+/// - Clicking on an item selects and activates it.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Key, KeyState, MouseButton, UpdateInfo, UpdateResult};
+/// use tuit::widgets::builtins::List;
+///
+/// let mut terminal: ConstantSize<20, 2> = ConstantSize::new();
+/// let mut menu = List::new(&["Boot", "Recovery", "Firmware setup"]).with_markers("> ", "  ").select_first();
+///
+/// let result = menu.update(UpdateInfo::KeyboardInput(Key::Down, KeyState::KeyDown), &terminal).unwrap();
+///
+/// assert_eq!(result, UpdateResult::Interacted);
+/// assert_eq!(menu.selected(), Some(1));
+///
+/// // There are only two rows, so selecting the last item scrolls the list down by one.
+/// menu.update(UpdateInfo::KeyboardInput(Key::End, KeyState::KeyDown), &terminal).unwrap();
+/// menu.drawn(&mut terminal).unwrap();
+///
+/// assert_eq!(terminal.cell(2, 0).unwrap().character, 'R');
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, '>');
+///
+/// // Clicking on "Recovery" selects and activates it.
+/// menu.update(UpdateInfo::CellClicked(4, 0, MouseButton::Primary), &terminal).unwrap();
+///
+/// assert_eq!(menu.activated(), Some(1));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug, Default)]
+pub struct List<'a, T> {
+    /// The items to display.
+    pub items: &'a [T],
+    /// The style to use for the selected item.
+    pub selected_item_style: Style,
+    /// The style to use for the unselected items.
+    pub unselected_item_style: Style,
+    /// The marker to display before the selected item.
+    pub selected_marker: &'a str,
+    /// The marker to display before the unselected items.
+    pub unselected_marker: &'a str,
+    /// The index of the currently selected item.
+    pub selected_item: Option<usize>,
+    /// The index of the item that was activated during the last update, if any.
+    pub activated_item: Option<usize>,
+    /// The index of the item at the top of the list.
+    scroll: usize,
+}
+
+impl<'a, T: AsRef<str>> List<'a, T> {
+    /// Create a new [`List`] widget with no markers and nothing selected.
+    ///
+    /// The selected item is drawn inverted by default.
+    #[must_use]
+    pub const fn new(items: &'a [T]) -> Self {
+        Self {
+            items,
+            selected_item_style: Style::new().inverted(),
+            unselected_item_style: Style::new(),
+            selected_marker: "",
+            unselected_marker: "",
+            selected_item: None,
+            activated_item: None,
+            scroll: 0,
+        }
+    }
+
+    /// Set the markers to display before the selected and unselected items. The shorter marker is
+    /// padded with spaces, so that the items stay lined up.
+    #[must_use]
+    pub const fn with_markers(mut self, selected: &'a str, unselected: &'a str) -> Self {
+        self.selected_marker = selected;
+        self.unselected_marker = unselected;
+
+        self
+    }
+
+    /// Set the styles to use for the selected and unselected items.
+    #[must_use]
+    pub const fn styled(mut self, selected: Style, unselected: Style) -> Self {
+        self.selected_item_style = selected;
+        self.unselected_item_style = unselected;
+
+        self
+    }
+
+    /// Returns the currently selected item. Will be `None` if no item is selected.
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected_item
+    }
+
+    /// Returns the item that was activated (by pressing Enter or Space, or by clicking on it) during
+    /// the last call to [`Widget::update`]. Will be `None` if no item was activated.
+    #[must_use]
+    pub const fn activated(&self) -> Option<usize> {
+        self.activated_item
+    }
+
+    /// Select an item based on its order from top-to-bottom.
+    #[must_use]
+    pub const fn select(mut self, selection: usize) -> Self {
+        if selection < self.items.len() {
+            self.selected_item = Some(selection);
+        }
+
+        self
+    }
+
+    /// Deselects the selected item.
+    #[must_use]
+    pub const fn select_none(mut self) -> Self {
+        self.selected_item = None;
+
+        self
+    }
+
+    /// Selects the first item, or `None` if there are no items.
+    #[must_use]
+    pub const fn select_first(self) -> Self {
+        self.select(0)
+    }
+
+    /// Selects the last item, or `None` if there are no items.
+    #[must_use]
+    pub const fn select_last(self) -> Self {
+        let Some(last) = self.items.len().checked_sub(1) else {
+            return self.select_none();
+        };
+
+        self.select(last)
+    }
+
+    /// Moves the selection down by `items` items, stopping at the last item. Selects the first item
+    /// if nothing is selected.
+    const fn select_next(&mut self, items: usize) {
+        let last = self.items.len().saturating_sub(1);
+
+        self.selected_item = Some(match self.selected_item {
+            None => 0,
+            Some(selected) => {
+                let next = selected.saturating_add(items);
+
+                if next > last { last } else { next }
+            }
+        });
+    }
+
+    /// Moves the selection up by `items` items, stopping at the first item. Selects the last item
+    /// if nothing is selected.
+    const fn select_previous(&mut self, items: usize) {
+        self.selected_item = Some(match self.selected_item {
+            None => self.items.len().saturating_sub(1),
+            Some(selected) => selected.saturating_sub(items),
+        });
+    }
+
+    /// Returns the width of the markers, which is the width of the wider one.
+    fn marker_width(&self) -> usize {
+        width::of_str(self.selected_marker).max(width::of_str(self.unselected_marker))
+    }
+
+    /// Returns the index of the item that needs to be at the top of the list to keep the selected
+    /// item visible in a terminal with `rows` rows.
+    fn scroll_for(&self, rows: usize) -> usize {
+        let Some(selected) = self.selected_item else {
+            return self.scroll.min(self.items.len().saturating_sub(rows));
+        };
+
+        if selected < self.scroll {
+            selected
+        } else if selected >= self.scroll + rows {
+            (selected + 1).saturating_sub(rows)
+        } else {
+            self.scroll
+        }
+    }
+}
+
+impl<T: AsRef<str>> Widget for List<'_, T> {
+    fn update(
+        &mut self,
+        update_info: UpdateInfo,
+        terminal: impl TerminalConst,
+    ) -> crate::Result<UpdateResult> {
+        self.activated_item = None;
+
+        if self.items.is_empty() {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        let rows = terminal.height().max(1);
+        let previous_selection = self.selected_item;
+
+        match update_info {
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => match key {
+                Key::Down => self.select_next(1),
+                Key::Up => self.select_previous(1),
+                Key::PageDown => self.select_next(rows),
+                Key::PageUp => self.select_previous(rows),
+                Key::Home => self.selected_item = Some(0),
+                Key::End => self.selected_item = Some(self.items.len() - 1),
+                Key::Enter => self.activated_item = self.selected_item,
+                _ => {}
+            },
+            UpdateInfo::KeyboardCharacter(' ', KeyState::KeyDown) => self.activated_item = self.selected_item,
+            UpdateInfo::MouseScrolled(_x, _y, ScrollDirection::Down) => self.select_next(1),
+            UpdateInfo::MouseScrolled(_x, _y, ScrollDirection::Up) => self.select_previous(1),
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) if x < terminal.width() => {
+                let clicked = self.scroll + y;
+
+                if y < rows && clicked < self.items.len() {
+                    self.selected_item = Some(clicked);
+                    self.activated_item = Some(clicked);
+                }
+            }
+            _ => {}
+        }
+
+        self.scroll = self.scroll_for(rows);
+
+        if self.activated_item.is_some() || self.selected_item != previous_selection {
+            Ok(UpdateResult::Interacted)
+        } else {
+            Ok(UpdateResult::NoEvent)
+        }
+    }
+
+    fn draw(
+        &self,
+        mut terminal: impl Terminal,
+    ) -> crate::Result<UpdateResult> {
+        let (row_width, rows) = terminal.dimensions();
+        let marker_width = self.marker_width();
+        let scroll = self.scroll_for(rows);
+
+        if row_width == 0 {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        for (row, item_idx) in (scroll..self.items.len()).take(rows).enumerate() {
+            let (style, marker) = if Some(item_idx) == self.selected_item {
+                (self.selected_item_style, self.selected_marker)
+            } else {
+                (self.unselected_item_style.inherits(self.selected_item_style.reset()), self.unselected_marker)
+            };
+
+            let area = Rectangle::new((0, row), (row_width, row + 1));
+            let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+            let mut writer = CellWriter::new(view.cells_mut(), row_width);
+
+            let padding = marker_width - width::of_str(marker);
+            let text = marker.chars().chain(core::iter::repeat_n(' ', padding)).chain(self.items[item_idx].as_ref().chars());
+
+            // Items that are wider than the terminal get truncated, and the rest of the row gets filled in.
+            for character in text.chain(core::iter::repeat(' ')) {
+                if writer.write(character, style).is_none() {
+                    break;
+                }
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn focusable(&self) -> usize {
+        usize::from(!self.items.is_empty())
+    }
+}
+
+impl<T: AsRef<str>> BoundingBox for List<'_, T> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let marker_width = self.marker_width();
+        let width = self
+            .items
+            .iter()
+            .map(|item| marker_width + width::of_str(item.as_ref()))
+            .max()
+            .unwrap_or(0);
+
+        Ok(Rectangle::of_size((width.min(rect.width()), self.items.len().min(rect.height()))))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        self.items.len() >= rectangle.height()
+    }
+}
@@ -1,15 +1,31 @@
 use crate::Error;
 use crate::prelude::{Metadata, Terminal, TerminalConst};
 use crate::terminal::{Rectangle, UpdateInfo, UpdateResult, View};
-use crate::widgets::{BoundingBox, Widget};
+use crate::widgets::{BoundingBox, Insets, Widget};
 
 /// Add a [`Margin`] to your widgets! Works by expanding the bounding box, meaning that it may fail
 /// to draw in certain cases. Try using [`ShrinkWrap`](crate::widgets::builtins::ShrinkWrap).
+///
+/// Each side can have its own margin:
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::Insets;
+/// use tuit::widgets::builtins::{Margin, Text};
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+///
+/// // One row above, and four columns to the left.
+/// Margin::new(Text::new("Hi")).insets(Insets::new(1, 0, 0, 4)).drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(4, 1).unwrap().character, 'H');
+/// ```
 pub struct Margin<T> {
     /// The child of the margin.
     child: T,
-    /// Padding in all directions
-    pub margin: isize
+    /// Padding in each direction
+    pub margin: Insets<isize>
 }
 
 impl<T> Margin<T> {
@@ -18,13 +34,21 @@ impl<T> Margin<T> {
     pub const fn new(child: T) -> Self {
         Self {
             child,
-            margin: 0
+            margin: Insets::all(0)
         }
     }
 
     /// Give the [`Margin`] a fixed amount of padding
     #[must_use]
     pub const fn margin(mut self, margin: isize) -> Self {
+        self.margin = Insets::all(margin);
+
+        self
+    }
+
+    /// Give the [`Margin`] a separate amount of padding on each side
+    #[must_use]
+    pub const fn insets(mut self, margin: Insets<isize>) -> Self {
         self.margin = margin;
 
         self
@@ -42,27 +66,7 @@ impl<T> Margin<T> {
 
     fn margin_view<U: Metadata>(&self, terminal: U) -> crate::Result<View<U>>
     where T: BoundingBox {
-        let rect = self.bounding_box_in(&terminal)?;
-        let ((rect_left, rect_top), (rect_right, rect_bottom)) = (rect.left_top(), rect.right_bottom());
-
-        let child_left = rect_left.checked_add_signed(self.margin).ok_or(Error::oob())?;
-        let child_top = rect_top.checked_add_signed(self.margin).ok_or(Error::oob())?;
-
-        let child_right = rect_right.checked_add_signed(-self.margin).ok_or(Error::oob())?;
-        let child_bottom = rect_bottom.checked_add_signed(-self.margin).ok_or(Error::oob())?;
-
-        let child = Rectangle::new((child_left, child_top), (child_right, child_bottom));
-
-        // let child_left = rect_left.checked_add_signed(self.margin).ok_or(Error::OutOfBoundsCoordinate {
-        //     x: None,
-        //     y: None,
-        // })?;
-        // let child_top = rect_top.checked_add_signed(self.margin).ok_or(Error::OutOfBoundsCoordinate {
-        //     x: None,
-        //     y: None,
-        // })?;
-        //
-        // let child = child.at((child_left, child_top));
+        let child = self.margin_rect(&terminal)?;
 
         let view = View::new(terminal, child).ok_or(Error::OutOfBoundsCoordinate {
             x: Some(child.right()),
@@ -71,6 +75,22 @@ impl<T> Margin<T> {
 
         Ok(view)
     }
+
+    fn margin_rect(&self, terminal: impl Metadata) -> crate::Result<Rectangle>
+    where T: BoundingBox {
+        let rect = self.bounding_box_in(&terminal)?;
+        let ((rect_left, rect_top), (rect_right, rect_bottom)) = (rect.left_top(), rect.right_bottom());
+
+        let child_left = rect_left.checked_add_signed(self.margin.left).ok_or(Error::oob())?;
+        let child_top = rect_top.checked_add_signed(self.margin.top).ok_or(Error::oob())?;
+
+        let child_right = rect_right.checked_add_signed(-self.margin.right).ok_or(Error::oob())?;
+        let child_bottom = rect_bottom.checked_add_signed(-self.margin.bottom).ok_or(Error::oob())?;
+
+        let child = Rectangle::new((child_left, child_top), (child_right, child_bottom));
+
+        Ok(child)
+    }
 }
 
 impl<T> BoundingBox for Margin<T>
@@ -78,13 +98,19 @@ where T: BoundingBox {
     fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
         let child = self.child.bounding_box(rect)?;
 
-        let Some(offset_child) = child.offset((self.margin, self.margin)) else {
+        let Some(offset_child) = child.offset((self.margin.left, self.margin.top)) else {
             return Err(Error::oob())
         };
 
-        let Some(fat_child) = offset_child.extend(self.margin) else {
-            // Should not fail after we offset the child.
-            unreachable!()
+        let fat_child = offset_child
+            .trim_left(-self.margin.left)
+            .and_then(|child| child.trim_top(-self.margin.top))
+            .and_then(|child| child.trim_right(-self.margin.right))
+            .and_then(|child| child.trim_bottom(-self.margin.bottom));
+
+        let Some(fat_child) = fat_child else {
+            // Can only fail if the right or bottom margin is negative enough to go past zero.
+            return Err(Error::oob())
         };
 
         let mut fat_child = fat_child;
@@ -159,23 +185,6 @@ where T: BoundingBox {
         log::trace!("We will be returning {fat_child:?} inside of {rect:?}");
 
 
-        // let margin_rect = self
-        //     .child
-        //     .bounding_box(rect)?
-        //     .trim_right(-self.margin * 2)
-        //     .and_then(|rect| rect.trim_bottom(-self.margin * 2))
-        //     .ok_or(Error::RequestRescale {
-        //         new_width: rect.width().saturating_add_signed(self.margin),
-        //         new_height: rect.height().saturating_add_signed(self.margin),
-        //     })?;
-        //
-        // if !rect.contains_rect(margin_rect) {
-        //     return Err(Error::RequestRescale {
-        //         new_width: margin_rect.right(),
-        //         new_height: margin_rect.bottom(),
-        //     })
-        // }
-
         Ok(fat_child)
     }
 
@@ -189,9 +198,10 @@ where T: BoundingBox {
 impl<T> Widget for Margin<T>
 where T: BoundingBox {
     fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let child_rect = self.margin_rect(&terminal)?;
         let view = self.margin_view(terminal)?;
 
-        self.child.update(update_info, view)
+        self.child.update(update_info.mouse_relative_to(child_rect), view)
     }
 
     fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
@@ -199,4 +209,57 @@ where T: BoundingBox {
 
         self.child.draw(view)
     }
-}
\ No newline at end of file
+
+    fn focusable(&self) -> usize {
+        self.child.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.child.set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.child.focused()
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::Margin;
+    use crate::terminal::Rectangle;
+    use crate::widgets::builtins::{Centered, Text};
+    use crate::widgets::{BoundingBox, Insets};
+    use crate::Error;
+
+    #[test]
+    fn negative_margins() {
+        let bounds = Rectangle::new((0, 0), (20, 5));
+
+        // A negative margin on the right or bottom eats into the child.
+        let margin = Margin::new(Text::new("Hello")).insets(Insets::new(0, -2, 0, 1));
+
+        assert_eq!(margin.bounding_box(bounds).expect("Should fit"), Rectangle::new((0, 0), (4, 1)));
+
+        let margin = Margin::new(Centered::new(Text::new("Hi"))).insets(Insets::new(2, 0, -1, 0));
+
+        assert_eq!(margin.bounding_box(bounds).expect("Should fit"), Rectangle::new((9, 2), (11, 4)));
+    }
+
+    #[test]
+    fn too_negative_margins() {
+        let bounds = Rectangle::new((0, 0), (20, 5));
+        let margin = Margin::new(Text::new("Hi")).insets(Insets::new(0, -3, 0, 0));
+
+        assert!(matches!(margin.bounding_box(bounds), Err(Error::OutOfBoundsCoordinate { x: None, y: None })));
+    }
+
+    #[test]
+    fn pushed_back_inside() {
+        let bounds = Rectangle::new((0, 0), (20, 5));
+        // The centred text is at (9, 2), so the margins push past the right and bottom edges.
+        let margin = Margin::new(Centered::new(Text::new("Hi"))).insets(Insets::new(0, 12, 3, 1));
+
+        assert_eq!(margin.bounding_box(bounds).expect("Should fit"), Rectangle::new((5, 1), (20, 5)));
+    }
+}
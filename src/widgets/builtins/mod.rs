@@ -4,13 +4,30 @@ pub use text::Text;
 pub use uv::Uv;
 pub use margin::Margin;
 pub use centered::Centered;
+pub use aligned::{Aligned, Anchor};
 pub use stacked::Stacked;
 pub use shelved::Shelved;
 pub use buttons::Buttons;
 pub use shrink_wrap::ShrinkWrap;
 pub use backdrop::Backdrop;
+pub use focus_ring::FocusRing;
+pub use paragraph::Paragraph;
+pub use rich_text::RichText;
+pub use text_input::TextInput;
+pub use text_buffer::{SliceBuffer, TextBuffer};
+pub use text_area::TextArea;
+pub use list::List;
+pub use table::{Column, ColumnWidth, Table};
+pub use border::{Border, Outline};
+pub use progress_bar::{ProgressBar, ProgressLabel};
+pub use spinner::Spinner;
+pub use canvas::{Canvas, Dots, Resolution};
+pub use children::Children;
+pub use flex::{Constraint, CrossAlignment, Flex, MainAlignment};
+pub use grid::{Grid, Placement, Track};
 use crate::style::{Colour, Style};
-use crate::widgets::BoundingBox;
+use crate::terminal::UpdateInfo;
+use crate::widgets::{BoundingBox, Widget};
 
 /// The code for the [`Sweeper`] widget.
 pub mod sweeper;
@@ -26,6 +43,8 @@ pub mod margin;
 pub mod dummy;
 /// The code for the [`Centered`] widget.
 pub mod centered;
+/// The code for the [`Aligned`] widget.
+pub mod aligned;
 /// The code for the [`Stacked`] widget.
 pub mod stacked;
 /// The code for the [`Buttons`] widget.
@@ -38,6 +57,37 @@ pub mod backdrop;
 pub mod shelved;
 /// The code for the [`Checkbox`] widget.
 pub mod checkbox;
+/// The code for the [`FocusRing`] widget.
+pub mod focus_ring;
+/// The code for the [`Paragraph`] widget.
+pub mod paragraph;
+/// The code for the [`RichText`] widget.
+pub mod rich_text;
+/// The code for the [`TextInput`] widget.
+pub mod text_input;
+/// The code for the [`TextBuffer`] trait and the [`SliceBuffer`] that implements it.
+pub mod text_buffer;
+/// The code for the [`TextArea`] widget.
+pub mod text_area;
+/// The code for the [`List`] widget.
+pub mod list;
+/// The code for the [`Table`] widget.
+pub mod table;
+/// The code for the [`Border`] widget.
+pub mod border;
+/// The code for the [`ProgressBar`] widget.
+pub mod progress_bar;
+/// The code for the [`Spinner`] widget.
+pub mod spinner;
+/// The code for the [`Canvas`] widget.
+pub mod canvas;
+/// The code for the [`Children`] trait, which lets layouts hold any number of widgets.
+pub mod children;
+/// The code for the [`Flex`] layout.
+pub mod flex;
+/// The code for the [`Grid`] layout.
+pub mod grid;
+mod wrap;
 
 impl<T: BoundingBox> From<T> for Centered<T> {
     fn from(value: T) -> Self {
@@ -102,7 +152,7 @@ pub trait WithLayout: Sized {
     fn with_shrink(self, shrink: usize) -> ShrinkWrap<Self> { ShrinkWrap::new(self).shrink(shrink) }
 
     /// Add a backdrop using the specified colour
-    fn use_backdrop(&self, bg_colour: Colour) -> Backdrop<Self> {
+    fn use_backdrop(&self, bg_colour: Colour) -> Backdrop<'_, Self> {
         Backdrop::new(self).with_style(Style::new().bg(bg_colour))
     }
 
@@ -111,6 +161,11 @@ pub trait WithLayout: Sized {
         Centered::new(self)
     }
 
+    /// Places the widget at the specified [`Anchor`], like the bottom-right corner.
+    fn anchored(self, anchor: Anchor) -> Aligned<Self> {
+        Aligned::new(self, anchor)
+    }
+
     /// Stacks the widget on top of another widget.
     fn on_top_of<T>(self, other: T) -> Stacked<Self, T> {
         Stacked::new(self, other)
@@ -120,6 +175,38 @@ pub trait WithLayout: Sized {
     fn next_to<T>(self, other: T) -> Shelved<Self, T> {
         Shelved::new(self, other)
     }
+
+    /// Draws a border around the widget using the specified [`Outline`].
+    fn bordered<'a>(self, outline: Outline) -> Border<'a, Self> {
+        Border::new(self).outline(outline)
+    }
+
+    /// Lets Tab and Shift+Tab move the keyboard focus between the focusable widgets inside of the widget.
+    fn with_focus_ring(self) -> FocusRing<Self>
+    where
+        Self: Widget {
+        FocusRing::new(self)
+    }
 }
 
 impl<T: BoundingBox> WithLayout for T {}
+
+/// Splits the focus of a widget with two children between them, where `first` is the number of
+/// focusable widgets inside of the first child.
+pub(crate) const fn split_focus(focus: Option<usize>, first: usize) -> (Option<usize>, Option<usize>) {
+    match focus {
+        Some(index) if index < first => (Some(index), None),
+        Some(index) => (None, Some(index - first)),
+        None => (None, None),
+    }
+}
+
+/// Withholds keyboard input from a child widget that doesn't hold the focus while one of its siblings
+/// does. Everything else is passed through unchanged.
+pub(crate) const fn route_focus(update_info: UpdateInfo, focus: Option<usize>, child_focus: Option<usize>) -> UpdateInfo {
+    if update_info.is_keyboard() && focus.is_some() && child_focus.is_none() {
+        return UpdateInfo::NoInfo;
+    }
+
+    update_info
+}
@@ -0,0 +1,143 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::Style;
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::wrap;
+use crate::widgets::{Alignment, BoundingBox, Overflow};
+
+/// Word-wrapped text at the top-left of the terminal.
+///
+/// Unlike [`Text`](super::Text), which breaks words wherever they reach the edge of the terminal,
+/// [`Paragraph`] moves words that don't fit onto the next line. Words that are wider than an entire
+/// line still get broken up. Newlines always start a new line.
+///
+/// Lines that don't fit in the terminal are cut off according to the [`Overflow`] policy instead of
+/// returning an error.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Rectangle};
+/// use tuit::widgets::BoundingBox;
+/// use tuit::widgets::builtins::Paragraph;
+///
+/// let mut terminal: ConstantSize<10, 5> = ConstantSize::new();
+/// let paragraph = Paragraph::new("The quick brown fox");
+///
+/// paragraph.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// // "The quick" fits on the first line, so "brown fox" goes onto the second.
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, 'b');
+/// assert_eq!(paragraph.bounding_box_in(&terminal).unwrap(), Rectangle::of_size((9, 2)));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Paragraph<'a> {
+    /// The text to display.
+    pub text: &'a str,
+    /// The style with which to display it.
+    pub style: Style,
+    /// How each line is aligned within the widest line.
+    pub alignment: Alignment,
+    /// What to do with lines that don't fit in the terminal.
+    pub overflow: Overflow,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Create a new left-aligned [`Paragraph`] with the default style, which clips any lines that
+    /// don't fit.
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            style: Style::new(),
+            alignment: Alignment::Left,
+            overflow: Overflow::Clip,
+        }
+    }
+
+    /// Apply a [`Style`] to the [`Paragraph`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the [`Alignment`] of the [`Paragraph`]. Lines are aligned within the widest line, so use
+    /// a layout widget like [`Centered`](super::Centered) to position the paragraph as a whole.
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::ConstantSize;
+    /// use tuit::widgets::Alignment;
+    /// use tuit::widgets::builtins::Paragraph;
+    ///
+    /// let mut terminal: ConstantSize<7, 3> = ConstantSize::new();
+    ///
+    /// Paragraph::new("one two three").aligned(Alignment::Right).drawn(&mut terminal).unwrap();
+    ///
+    /// // "one two" is the widest line, so "three" gets pushed over to line up with its end.
+    /// assert_eq!(terminal.cell(2, 1).unwrap().character, 't');
+    ///
+    /// Paragraph::new("one two three").aligned(Alignment::Justified).drawn(&mut terminal).unwrap();
+    ///
+    /// assert_eq!(terminal.cell(6, 0).unwrap().character, 'o');
+    /// ```
+    #[must_use]
+    pub const fn aligned(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Set the [`Overflow`] policy of the [`Paragraph`].
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::ConstantSize;
+    /// use tuit::widgets::Overflow;
+    /// use tuit::widgets::builtins::Paragraph;
+    ///
+    /// let mut terminal: ConstantSize<5, 1> = ConstantSize::new();
+    ///
+    /// Paragraph::new("Hello, world!").overflow(Overflow::Ellipsis).drawn(&mut terminal).unwrap();
+    ///
+    /// assert_eq!(terminal.cell(0, 0).unwrap().character, 'H');
+    /// assert_eq!(terminal.cell(4, 0).unwrap().character, '…');
+    /// ```
+    #[must_use]
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Returns the characters of the [`Paragraph`] along with their style.
+    fn chars(&self) -> impl Iterator<Item = (char, Style)> + Clone + 'a {
+        let style = self.style;
+
+        self.text.chars().map(move |character| (character, style))
+    }
+}
+
+impl Widget for Paragraph<'_> {
+    fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        wrap::draw(self.chars(), self.alignment, self.overflow, terminal)?;
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for Paragraph<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let dimensions = wrap::dimensions(self.chars(), self.overflow, rect);
+
+        Ok(Rectangle::of_size(dimensions))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        wrap::covers(rectangle)
+    }
+}
@@ -0,0 +1,264 @@
+use crate::prelude::{Terminal, TerminalConst};
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Rectangle, TerminalMut, UpdateInfo, UpdateResult};
+use crate::widgets::{Axis, BoundingBox, Widget};
+use crate::Error;
+
+/// Partially filled cells of a horizontal bar, from one eighth to seven eighths.
+const HORIZONTAL_EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+/// Partially filled cells of a vertical bar, from one eighth to seven eighths.
+const VERTICAL_EIGHTHS: [char; 7] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+
+/// The text drawn in the middle of a [`ProgressBar`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum ProgressLabel<'a> {
+    /// No text is drawn.
+    #[default]
+    None,
+    /// The progress is drawn as a whole percentage, like `42%`.
+    Percentage,
+    /// The given text is drawn.
+    Text(&'a str),
+}
+
+/// A bar that fills up as progress is made, like a gauge.
+///
+/// The bar takes up all of the space it's given, and fills up left-to-right when it's
+/// [`Axis::Horizontal`], or bottom-to-top when it's [`Axis::Vertical`]. The edge of the filled part
+/// is drawn with eighth-block characters, so that the bar moves smoothly even when it's only a few
+/// cells long. Terminals that can't display those can use [`ProgressBar::ascii`] instead.
+///
+/// The label is centred on the middle row of the bar. Where it overlaps the filled part, it's drawn
+/// with [`ProgressBar::filled_style`] inverted, so that it stays readable.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::{ProgressBar, ProgressLabel};
+///
+/// let mut terminal: ConstantSize<10, 1> = ConstantSize::new();
+///
+/// let progress = ProgressBar::new(0.4375).labeled(ProgressLabel::Percentage);
+///
+/// progress.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// // 43.75% of 10 cells is four and three eighths cells.
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, '█');
+/// assert_eq!(terminal.cell(3, 0).unwrap().character, '4');
+/// assert_eq!(terminal.cell(4, 0).unwrap().character, '3');
+/// assert_eq!(terminal.cell(5, 0).unwrap().character, '%');
+///
+/// let mut terminal: ConstantSize<1, 4> = ConstantSize::new();
+///
+/// ProgressBar::new(0.5).vertical().drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(0, 3).unwrap().character, '█');
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, ' ');
+///
+/// ProgressBar::new(0.5625).vertical().drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, '▂');
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ProgressBar<'a> {
+    /// How much of the bar is filled, from `0.0` to `1.0`. Values outside of that range are clamped.
+    pub ratio: f32,
+    /// The axis that the bar fills up along.
+    pub axis: Axis,
+    /// The text drawn in the middle of the bar.
+    pub label: ProgressLabel<'a>,
+    /// Whether the bar is drawn with `#` and `-` instead of block characters.
+    pub ascii: bool,
+    /// The style of the filled part of the bar.
+    pub filled_style: Style,
+    /// The style of the empty part of the bar.
+    pub empty_style: Style,
+    /// The style of the label. Properties that aren't set are taken from the part of the bar that
+    /// the label is drawn over.
+    pub label_style: Style,
+}
+
+impl<'a> ProgressBar<'a> {
+    /// Create a new, horizontal [`ProgressBar`] without a label.
+    #[must_use]
+    pub const fn new(ratio: f32) -> Self {
+        Self {
+            ratio,
+            axis: Axis::Horizontal,
+            label: ProgressLabel::None,
+            ascii: false,
+            filled_style: Style::new(),
+            empty_style: Style::new(),
+            label_style: Style::new(),
+        }
+    }
+
+    /// Create a new [`ProgressBar`] that is `done` out of `total` of the way full.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Progress doesn't need to be exact.
+    pub fn of(done: usize, total: usize) -> Self {
+        Self::new(if total == 0 { 1.0 } else { done as f32 / total as f32 })
+    }
+
+    /// Make the [`ProgressBar`] fill up bottom-to-top.
+    #[must_use]
+    pub const fn vertical(mut self) -> Self {
+        self.axis = Axis::Vertical;
+
+        self
+    }
+
+    /// Set the text drawn in the middle of the [`ProgressBar`].
+    #[must_use]
+    pub const fn labeled(mut self, label: ProgressLabel<'a>) -> Self {
+        self.label = label;
+
+        self
+    }
+
+    /// Draw the [`ProgressBar`] using only ASCII characters.
+    #[must_use]
+    pub const fn ascii(mut self) -> Self {
+        self.ascii = true;
+
+        self
+    }
+
+    /// Set the styles of the filled and empty parts of the [`ProgressBar`].
+    #[must_use]
+    pub const fn styled(mut self, filled: Style, empty: Style) -> Self {
+        self.filled_style = filled;
+        self.empty_style = empty;
+
+        self
+    }
+
+    /// Set the style of the label.
+    #[must_use]
+    pub const fn label_styled(mut self, style: Style) -> Self {
+        self.label_style = style;
+
+        self
+    }
+
+    /// Returns how many eighths of a cell are filled in a bar that is `length` cells long.
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn filled_eighths(&self, length: usize) -> usize {
+        // Also turns NaN into zero, since the cast saturates.
+        (self.ratio.clamp(0.0, 1.0) * (length * 8) as f32) as usize
+    }
+
+    /// Returns the character and style of the cell at `position` cells along the bar, counting from
+    /// where it starts filling up, and whether the cell is at least half full.
+    const fn cell_at(&self, position: usize, filled_eighths: usize) -> (char, Style, bool) {
+        let (full_cells, eighths) = (filled_eighths / 8, filled_eighths % 8);
+        let empty = if self.ascii { '-' } else { ' ' };
+
+        if position < full_cells {
+            (if self.ascii { '#' } else { '█' }, self.filled_style, true)
+        } else if position > full_cells || eighths == 0 {
+            (empty, self.empty_style, false)
+        } else if self.ascii {
+            // ASCII has no partially filled cells, so round to the nearest whole cell.
+            if eighths >= 4 { ('#', self.filled_style, true) } else { (empty, self.empty_style, false) }
+        } else {
+            let partial = match self.axis {
+                Axis::Horizontal => HORIZONTAL_EIGHTHS[eighths - 1],
+                Axis::Vertical => VERTICAL_EIGHTHS[eighths - 1],
+            };
+
+            // The rest of the cell should look like the empty part of the bar.
+            (partial, self.filled_style.inherits(self.empty_style), eighths >= 4)
+        }
+    }
+
+    /// Returns the text of the label, using `digits` as storage for the percentage.
+    fn label_text<'b>(&'b self, digits: &'b mut [u8; 4]) -> &'b str {
+        match self.label {
+            ProgressLabel::None => "",
+            ProgressLabel::Text(text) => text,
+            ProgressLabel::Percentage => {
+                let mut percentage = self.filled_eighths(100) / 8;
+                let mut start = digits.len() - 1;
+
+                digits[start] = b'%';
+
+                loop {
+                    start -= 1;
+                    // Always a single digit, so it can't truncate.
+                    #[allow(clippy::cast_possible_truncation)]
+                    let digit = (percentage % 10) as u8;
+                    digits[start] = b'0' + digit;
+                    percentage /= 10;
+
+                    if percentage == 0 {
+                        break;
+                    }
+                }
+
+                core::str::from_utf8(&digits[start..]).unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl Widget for ProgressBar<'_> {
+    fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let (row_width, rows) = terminal.dimensions();
+        let filled_eighths = self.filled_eighths(match self.axis {
+            Axis::Horizontal => row_width,
+            Axis::Vertical => rows,
+        });
+
+        let mut digits = [0; 4];
+        let label = self.label_text(&mut digits);
+        let label_start = row_width.saturating_sub(width::of_str(label)) / 2;
+
+        for y in 0..rows {
+            let area = Rectangle::new((0, y), (row_width, y + 1));
+            let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+            let mut writer = CellWriter::new(view.cells_mut(), row_width);
+            let mut label_chars = label.chars().filter(|_| y == rows / 2).peekable();
+
+            while writer.column() < row_width {
+                let x = writer.column();
+                let (character, style, filled) = match self.axis {
+                    Axis::Horizontal => self.cell_at(x, filled_eighths),
+                    Axis::Vertical => self.cell_at(rows - 1 - y, filled_eighths),
+                };
+
+                let written = match label_chars.peek() {
+                    Some(&label_char) if x >= label_start => {
+                        label_chars.next();
+
+                        let under = if filled { self.filled_style.inverted() } else { self.empty_style };
+
+                        writer.write(label_char, self.label_style.inherits(under))
+                    }
+                    _ => writer.write(character, style),
+                };
+
+                if written.is_none() {
+                    break;
+                }
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for ProgressBar<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(rect)
+    }
+
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        true
+    }
+}
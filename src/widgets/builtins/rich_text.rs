@@ -0,0 +1,133 @@
+use crate::prelude::{Terminal, TerminalConst, Widget};
+use crate::style::Style;
+use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::wrap;
+use crate::widgets::{Alignment, BoundingBox, Overflow};
+
+/// Word-wrapped text made up of spans that each have their own [`Style`].
+///
+/// The spans get wrapped as if they were one string, so a word can start in one span and end in
+/// another. Each span's style inherits any unset fields from [`RichText::style`], and then from the
+/// cells that it gets drawn over (see [`Style::inherits`]).
+///
+/// Apart from the spans, [`RichText`] behaves exactly like a [`Paragraph`](super::Paragraph).
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::style::{Ansi4, Style};
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::builtins::RichText;
+///
+/// let mut terminal: ConstantSize<20, 2> = ConstantSize::new();
+///
+/// let error = Style::new().fg_ansi4(Ansi4::Red).font_weight(700);
+/// let spans = [("[", Style::new()), ("ERROR", error), ("] Disk full", Style::new())];
+///
+/// RichText::new(&spans).drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(1, 0).unwrap().character, 'E');
+/// assert_eq!(terminal.cell(1, 0).unwrap().style.fg_colour, error.fg_colour);
+/// assert_eq!(terminal.cell(6, 0).unwrap().character, ']');
+/// assert_eq!(terminal.cell(6, 0).unwrap().style.fg_colour, None);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RichText<'a> {
+    /// The spans of text to display, along with their styles.
+    pub spans: &'a [(&'a str, Style)],
+    /// The style that every span inherits from.
+    pub style: Style,
+    /// How each line is aligned within the widest line.
+    pub alignment: Alignment,
+    /// What to do with lines that don't fit in the terminal.
+    pub overflow: Overflow,
+}
+
+impl<'a> RichText<'a> {
+    /// Create a new left-aligned [`RichText`] with no base style, which clips any lines that don't fit.
+    #[must_use]
+    pub const fn new(spans: &'a [(&'a str, Style)]) -> Self {
+        Self {
+            spans,
+            style: Style::new(),
+            alignment: Alignment::Left,
+            overflow: Overflow::Clip,
+        }
+    }
+
+    /// Set the style that every span inherits from.
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::style::{Ansi4, Style};
+    /// use tuit::terminal::ConstantSize;
+    /// use tuit::widgets::builtins::RichText;
+    ///
+    /// let mut terminal: ConstantSize<20, 2> = ConstantSize::new();
+    ///
+    /// let spans = [("plain ", Style::new()), ("underlined", Style::new().underlined())];
+    /// let rich_text = RichText::new(&spans).styled(Style::new().bg_ansi4(Ansi4::Blue));
+    ///
+    /// rich_text.drawn(&mut terminal).expect("Should draw successfully");
+    ///
+    /// // Both spans get the blue background.
+    /// assert_eq!(terminal.cell(0, 0).unwrap().style.bg_colour, rich_text.style.bg_colour);
+    /// assert_eq!(terminal.cell(6, 0).unwrap().style.bg_colour, rich_text.style.bg_colour);
+    /// ```
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the [`Alignment`] of the [`RichText`]. Lines are aligned within the widest line.
+    #[must_use]
+    pub const fn aligned(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Set the [`Overflow`] policy of the [`RichText`].
+    #[must_use]
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Returns the characters of every span along with their style.
+    fn chars(&self) -> impl Iterator<Item = (char, Style)> + Clone + 'a {
+        let base = self.style;
+
+        self.spans.iter().flat_map(move |&(text, style)| {
+            let style = style.inherits(base);
+
+            text.chars().map(move |character| (character, style))
+        })
+    }
+}
+
+impl Widget for RichText<'_> {
+    fn update(&mut self, _update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn draw(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        wrap::draw(self.chars(), self.alignment, self.overflow, terminal)?;
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for RichText<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let dimensions = wrap::dimensions(self.chars(), self.overflow, rect);
+
+        Ok(Rectangle::of_size(dimensions))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        wrap::covers(rectangle)
+    }
+}
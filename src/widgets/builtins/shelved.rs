@@ -1,6 +1,7 @@
 use crate::Error;
 use crate::prelude::Metadata;
 use crate::terminal::{Rectangle, Terminal, TerminalConst, UpdateInfo, UpdateResult, View};
+use crate::widgets::builtins::{route_focus, split_focus};
 use crate::widgets::{BoundingBox, Widget};
 
 /// A widget that shelves two widgets next to each other.
@@ -11,12 +12,15 @@ pub struct Shelved<LEFT, RIGHT> {
     pub right_widget: RIGHT,
     /// The leftover update from the last [`Widget::update`] call.
     pub leftover_result: Option<UpdateResult>,
+    /// The index of the focused widget, counting the focusable widgets inside of the left widget first.
+    /// It's kept in sync with the children's focus by [`Widget::set_focus`].
+    focus: Option<usize>,
 }
 
 impl<LEFT, RIGHT> Shelved<LEFT, RIGHT> {
-    /// Create a new [`Shelved`] widget.
+    /// Create a new [`Shelved`] widget, with nothing focused.
     pub const fn new(left: LEFT, right: RIGHT) -> Self {
-        Self { left_widget: left, right_widget: right, leftover_result: None }
+        Self { left_widget: left, right_widget: right, leftover_result: None, focus: None }
     }
 
     /// Returns the bounding box of the left widget.
@@ -164,11 +168,15 @@ impl<LEFT: BoundingBox, RIGHT: BoundingBox> Widget for Shelved<LEFT, RIGHT> {
     fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
         let left_view_rect = self.left_view_rect(terminal.bounding_box())?;
         let left_view = self.get_view_left(&terminal)?;
-        let left_update =  self.left_widget.update(update_info.mouse_relative_to(left_view_rect), left_view)?;
+        let (left_focus, right_focus) = split_focus(self.focus, self.left_widget.focusable());
+
+        let left_update_info = route_focus(update_info, self.focus, left_focus);
+        let left_update = self.left_widget.update(left_update_info.mouse_relative_to(left_view_rect), left_view)?;
 
         let right_view_rect = self.right_view_rect(terminal.bounding_box())?;
         let right_view = self.get_view_right(&terminal)?;
-        let right_update = self.right_widget.update(update_info.mouse_relative_to(right_view_rect), right_view)?;
+        let right_update_info = route_focus(update_info, self.focus, right_focus);
+        let right_update = self.right_widget.update(right_update_info.mouse_relative_to(right_view_rect), right_view)?;
 
         self.leftover_result = Some(left_update.min(right_update));
 
@@ -184,6 +192,23 @@ impl<LEFT: BoundingBox, RIGHT: BoundingBox> Widget for Shelved<LEFT, RIGHT> {
 
         Ok(left_update.max(right_update))
     }
+
+    fn focusable(&self) -> usize {
+        self.left_widget.focusable() + self.right_widget.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.focus = focus.filter(|&index| index < self.focusable());
+
+        let (left_focus, right_focus) = split_focus(self.focus, self.left_widget.focusable());
+
+        self.left_widget.set_focus(left_focus);
+        self.right_widget.set_focus(right_focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.focus
+    }
 }
 
 impl<LEFT: BoundingBox, RIGHT: BoundingBox> BoundingBox for Shelved<LEFT, RIGHT> {
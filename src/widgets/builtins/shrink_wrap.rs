@@ -1,14 +1,35 @@
 use crate::Error;
 use crate::prelude::{Terminal, TerminalConst, Widget};
 use crate::terminal::{Metadata, Rectangle, UpdateInfo, UpdateResult, View};
-use crate::widgets::BoundingBox;
+use crate::widgets::{BoundingBox, Insets};
 
 /// Adds padding to a [`BoundingBox`] widget by pretending that the terminal is smaller than it actually is
+///
+/// Each side can have its own padding:
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::Insets;
+/// use tuit::widgets::builtins::{ShrinkWrap, Sweeper};
+/// use tuit::style::Colour;
+///
+/// let mut terminal: ConstantSize<10, 5> = ConstantSize::new();
+///
+/// // No padding above and below, and two columns on the left and right.
+/// let sweeper = Sweeper::of_colour(Colour::Rgb24(255, 0, 0));
+/// ShrinkWrap::new(sweeper).insets(Insets::symmetric(0, 2)).drawn(&mut terminal).expect("Should draw successfully");
+///
+/// assert_eq!(terminal.cell(1, 0).unwrap().style.bg_colour, None);
+/// assert_eq!(terminal.cell(2, 0).unwrap().style.bg_colour, Some(Colour::Rgb24(255, 0, 0)));
+/// assert_eq!(terminal.cell(7, 4).unwrap().style.bg_colour, Some(Colour::Rgb24(255, 0, 0)));
+/// assert_eq!(terminal.cell(8, 4).unwrap().style.bg_colour, None);
+/// ```
 pub struct ShrinkWrap<T> {
     /// The child widget
     child: T,
-    /// How much padding to apply
-    pub padding: usize
+    /// How much padding to apply on each side
+    pub padding: Insets<usize>
 }
 
 impl<T> ShrinkWrap<T> {
@@ -19,13 +40,21 @@ impl<T> ShrinkWrap<T> {
     pub const fn new(child: T) -> Self {
         Self {
             child,
-            padding: 0
+            padding: Insets::all(0)
         }
     }
 
     /// Set the padding for the widget.
     #[must_use]
     pub const fn shrink(mut self, padding: usize) -> Self {
+        self.padding = Insets::all(padding);
+
+        self
+    }
+
+    /// Set a separate padding for each side of the widget.
+    #[must_use]
+    pub const fn insets(mut self, padding: Insets<usize>) -> Self {
         self.padding = padding;
 
         self
@@ -36,7 +65,7 @@ impl<T> ShrinkWrap<T> {
         &self.child
     }
 
-    /// Returns the
+    /// Returns the [`View`] that the child widget is drawn into, inside of the padding.
     ///
     /// # Errors
     ///
@@ -69,16 +98,32 @@ impl<T: Widget> Widget for ShrinkWrap<T> {
 
         self.child.draw(view)
     }
+
+    fn focusable(&self) -> usize {
+        self.child.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.child.set_focus(focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.child.focused()
+    }
 }
 
 impl<T: Widget> BoundingBox for ShrinkWrap<T> {
     fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let Insets { top, right, bottom, left } = self.padding;
+
         rect
-            .trim_x(self.padding as isize)
-            .and_then(|rect| rect.trim_y(self.padding as isize))
+            .trim_left(left as isize)
+            .and_then(|rect| rect.trim_right(right as isize))
+            .and_then(|rect| rect.trim_top(top as isize))
+            .and_then(|rect| rect.trim_bottom(bottom as isize))
             .ok_or(Error::RequestRescale {
-                new_width: rect.width().saturating_add_signed(self.padding as isize),
-                new_height: rect.height().saturating_add_signed(self.padding as isize),
+                new_width: rect.width().saturating_add(left).saturating_add(right),
+                new_height: rect.height().saturating_add(top).saturating_add(bottom),
             })
     }
 
@@ -0,0 +1,202 @@
+use core::time::Duration;
+
+use crate::prelude::{Terminal, TerminalConst};
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Rectangle, TerminalMut, UpdateInfo, UpdateResult};
+use crate::widgets::{BoundingBox, Widget};
+use crate::Error;
+
+/// A widget that cycles through a set of frames as time passes, to show that something is going on.
+///
+/// The spinner only moves when it receives [`UpdateInfo::TimeDelta`]s. They're added up, and the
+/// spinner advances by one frame for every [`Spinner::interval`] that has passed. When that
+/// happens, [`Widget::update`] returns [`UpdateResult::Interacted`] to signal that the spinner
+/// needs to be redrawn.
+///
+/// An optional label is drawn after the spinner, separated by a space.
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, UpdateInfo, UpdateResult};
+/// use tuit::widgets::builtins::Spinner;
+///
+/// let mut terminal: ConstantSize<20, 1> = ConstantSize::new();
+/// let mut spinner = Spinner::new(Spinner::ASCII).every(Duration::from_millis(100)).labeled("Flashing");
+///
+/// // Not enough time has passed to move on to the next frame.
+/// let result = spinner.update(UpdateInfo::TimeDelta(Duration::from_millis(60)), &terminal).unwrap();
+/// assert_eq!(result, UpdateResult::NoEvent);
+///
+/// // The time adds up, so now it has.
+/// let result = spinner.update(UpdateInfo::TimeDelta(Duration::from_millis(60)), &terminal).unwrap();
+/// assert_eq!(result, UpdateResult::Interacted);
+///
+/// spinner.drawn(&mut terminal).unwrap();
+///
+/// assert_eq!(terminal.cell(0, 0).unwrap().character, '\\');
+/// assert_eq!(terminal.cell(2, 0).unwrap().character, 'F');
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Spinner<'a> {
+    /// The frames to cycle through.
+    pub frames: &'a [&'a str],
+    /// How long each frame is shown for.
+    pub interval: Duration,
+    /// The text drawn after the spinner.
+    pub label: &'a str,
+    /// The style of the spinner and its label.
+    pub style: Style,
+    /// The index of the current frame.
+    frame: usize,
+    /// How much time has passed since the current frame was first shown.
+    elapsed: Duration,
+}
+
+impl<'a> Spinner<'a> {
+    /// A dot spinning around a braille cell: `⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`.
+    pub const BRAILLE: &'static [&'static str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+    /// A line rotating with box-drawing characters: `─╲│╱`.
+    pub const LINE: &'static [&'static str] = &["─", "╲", "│", "╱"];
+    /// Dots that fill up and empty out, three cells wide.
+    pub const DOTS: &'static [&'static str] = &["   ", "·  ", "·· ", "···", " ··", "  ·"];
+    /// A line rotating with ASCII characters, for terminals without Unicode: `-\|/`.
+    pub const ASCII: &'static [&'static str] = &["-", "\\", "|", "/"];
+
+    /// Create a new [`Spinner`] that cycles through `frames`, showing each for 100 milliseconds.
+    #[must_use]
+    pub const fn new(frames: &'a [&'a str]) -> Self {
+        Self {
+            frames,
+            interval: Duration::from_millis(100),
+            label: "",
+            style: Style::new(),
+            frame: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Set how long each frame is shown for. A [`Spinner`] with an interval of zero doesn't move.
+    #[must_use]
+    pub const fn every(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Set the text drawn after the [`Spinner`].
+    #[must_use]
+    pub const fn labeled(mut self, label: &'a str) -> Self {
+        self.label = label;
+
+        self
+    }
+
+    /// Set the style of the [`Spinner`] and its label.
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Returns the index of the current frame.
+    #[must_use]
+    pub const fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Go back to the first frame.
+    pub const fn reset(&mut self) {
+        self.frame = 0;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Returns the width of the widest frame, so that the label doesn't move around.
+    fn frame_width(&self) -> usize {
+        self.frames.iter().map(|frame| width::of_str(frame)).max().unwrap_or(0)
+    }
+
+    /// Returns the total width of the spinner and its label.
+    fn width(&self) -> usize {
+        if self.label.is_empty() {
+            self.frame_width()
+        } else {
+            self.frame_width() + 1 + width::of_str(self.label)
+        }
+    }
+}
+
+impl Widget for Spinner<'_> {
+    fn update(&mut self, update_info: UpdateInfo, _terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let UpdateInfo::TimeDelta(delta) = update_info else {
+            return Ok(UpdateResult::NoEvent);
+        };
+
+        let interval = self.interval.as_nanos();
+
+        if interval == 0 || self.frames.is_empty() {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        self.elapsed = self.elapsed.saturating_add(delta);
+
+        let elapsed = self.elapsed.as_nanos();
+        let frames = u128::try_from(self.frames.len()).unwrap_or(u128::MAX);
+        let steps = usize::try_from(elapsed / interval % frames).unwrap_or(0);
+
+        // Only a remainder of more than ~584 years could fail to fit.
+        self.elapsed = Duration::from_nanos(u64::try_from(elapsed % interval).unwrap_or(u64::MAX));
+
+        if elapsed < interval {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        self.frame = (self.frame + steps) % self.frames.len();
+
+        Ok(UpdateResult::Interacted)
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let (row_width, rows) = terminal.dimensions();
+
+        if rows == 0 {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        let frame = self.frames.get(self.frame).copied().unwrap_or_default();
+        let padding = self.frame_width() - width::of_str(frame);
+        let label_separator = if self.label.is_empty() { "" } else { " " };
+
+        let text = frame
+            .chars()
+            .chain(core::iter::repeat_n(' ', padding))
+            .chain(label_separator.chars())
+            .chain(self.label.chars());
+
+        let area = Rectangle::of_size((row_width, 1));
+        let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+        let mut writer = CellWriter::new(view.cells_mut(), row_width);
+
+        // Labels that are wider than the terminal get truncated.
+        for character in text {
+            if writer.write(character, self.style).is_none() {
+                break;
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+}
+
+impl BoundingBox for Spinner<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(Rectangle::of_size((self.width().min(rect.width()), 1.min(rect.height()))))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        rectangle.height() <= 1 && self.width() >= rectangle.width()
+    }
+}
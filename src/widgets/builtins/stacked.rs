@@ -1,6 +1,7 @@
 use crate::Error;
 use crate::prelude::{ Terminal, TerminalConst, Widget};
 use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::{route_focus, split_focus};
 use crate::widgets::BoundingBox;
 
 /// The [`Stacked`] widget lets you lay out one widget on top of another.
@@ -12,12 +13,15 @@ pub struct Stacked<TOP, BOT> {
     pub lower_widget: BOT,
     /// The leftover update from the last [`Widget::update`] call.
     pub leftover_result: Option<UpdateResult>,
+    /// The index of the focused widget, counting the focusable widgets inside of the top widget first.
+    /// It's kept in sync with the children's focus by [`Widget::set_focus`].
+    focus: Option<usize>,
 }
 
 impl<TOP, BOT> Stacked<TOP, BOT> {
-    /// Create a new [`Stacked`] widget.
+    /// Create a new [`Stacked`] widget, with nothing focused.
     pub const fn new(top: TOP, bottom: BOT) -> Self {
-        Self { higher_widget: top, lower_widget: bottom, leftover_result: None }
+        Self { higher_widget: top, lower_widget: bottom, leftover_result: None, focus: None }
     }
 
     /// Draws the bottom widget, and returns its update result. This is better than using [`Widget::draw`]
@@ -198,14 +202,18 @@ impl<TOP: BoundingBox, BOT: BoundingBox> Widget for Stacked<TOP, BOT> {
             y: Some(higher_view_rect.bottom())
         })?;
 
-        let higher_update = self.higher_widget.update(update_info.mouse_relative_to(higher_view_rect), higher_view);
+        let (higher_focus, lower_focus) = split_focus(self.focus, self.higher_widget.focusable());
+
+        let higher_update_info = route_focus(update_info, self.focus, higher_focus);
+        let higher_update = self.higher_widget.update(higher_update_info.mouse_relative_to(higher_view_rect), higher_view);
 
         let lower_view = terminal.view(lower_view_rect).ok_or(Error::OutOfBoundsCoordinate {
             x: Some(lower_view_rect.right()),
             y: Some(lower_view_rect.bottom())
         })?;
 
-        let lower_update = self.lower_widget.update(update_info.mouse_relative_to(lower_view_rect), lower_view);
+        let lower_update_info = route_focus(update_info, self.focus, lower_focus);
+        let lower_update = self.lower_widget.update(lower_update_info.mouse_relative_to(lower_view_rect), lower_view);
 
         let res_higher = higher_update?;
         let res_lower = lower_update?;
@@ -221,6 +229,23 @@ impl<TOP: BoundingBox, BOT: BoundingBox> Widget for Stacked<TOP, BOT> {
 
         Ok(res_lower.max(res_higher))
     }
+
+    fn focusable(&self) -> usize {
+        self.higher_widget.focusable() + self.lower_widget.focusable()
+    }
+
+    fn set_focus(&mut self, focus: Option<usize>) {
+        self.focus = focus.filter(|&index| index < self.focusable());
+
+        let (higher_focus, lower_focus) = split_focus(self.focus, self.higher_widget.focusable());
+
+        self.higher_widget.set_focus(higher_focus);
+        self.lower_widget.set_focus(lower_focus);
+    }
+
+    fn focused(&self) -> Option<usize> {
+        self.focus
+    }
 }
 
 impl<TOP: BoundingBox, BOT: BoundingBox> BoundingBox for Stacked<TOP, BOT> {
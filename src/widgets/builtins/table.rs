@@ -0,0 +1,506 @@
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Key, KeyState, MouseButton, Rectangle, ScrollDirection, Terminal, TerminalConst, TerminalMut, UpdateInfo, UpdateResult};
+use crate::widgets::{Alignment, BoundingBox, Overflow, Widget};
+use crate::Error;
+
+/// How wide a [`Column`] of a [`Table`] is.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ColumnWidth {
+    /// Exactly this many cells wide.
+    Fixed(usize),
+    /// This percentage of the table's width, not counting separators.
+    Percentage(usize),
+    /// As wide as the widest cell in the column, including the header.
+    Fit,
+}
+
+/// A column of a [`Table`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Column<'a> {
+    /// The text in the header row.
+    pub header: &'a str,
+    /// How wide the column is.
+    pub width: ColumnWidth,
+    /// How the text is aligned within each cell. [`Alignment::Justified`] is treated like
+    /// [`Alignment::Left`].
+    pub alignment: Alignment,
+}
+
+impl<'a> Column<'a> {
+    /// Create a new left-aligned [`Column`] that fits its content.
+    #[must_use]
+    pub const fn new(header: &'a str) -> Self {
+        Self {
+            header,
+            width: ColumnWidth::Fit,
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Set how wide the [`Column`] is.
+    #[must_use]
+    pub const fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = width;
+
+        self
+    }
+
+    /// Set how the text is aligned within each cell of the [`Column`].
+    #[must_use]
+    pub const fn aligned(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+}
+
+/// A widget that displays rows of text in columns, below a header row. One of the rows can be selected.
+///
+/// Each row is anything that can be borrowed as a slice of `&str`s, like an array, with one string
+/// per [`Column`]. Missing cells are left empty, and extra cells are ignored. Cells that don't fit
+/// in their column are cut off according to [`Table::overflow`], and columns that don't fit in
+/// the terminal are cut off at its right edge.
+///
+/// The rows scroll to keep the selected row in view, while the header stays in place. Selecting rows
+/// works just like selecting the items of a [`List`](super::List).
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::ConstantSize;
+/// use tuit::widgets::Alignment;
+/// use tuit::widgets::builtins::{Column, ColumnWidth, Table};
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+///
+/// let columns = [
+///     Column::new("Name"),
+///     Column::new("MTU").width(ColumnWidth::Fixed(5)).aligned(Alignment::Right),
+/// ];
+/// let rows = [["eth0", "1500"], ["lo", "65536"]];
+///
+/// let table = Table::new(&columns, &rows).with_separators(" | ", Some('-'));
+///
+/// table.drawn(&mut terminal).expect("Should draw successfully");
+///
+/// // The "Name" column is as wide as "Name", and the MTU column is five cells wide.
+/// assert_eq!(terminal.cell(4, 0).unwrap().character, ' ');
+/// assert_eq!(terminal.cell(5, 0).unwrap().character, '|');
+/// assert_eq!(terminal.cell(0, 1).unwrap().character, '-');
+/// assert_eq!(terminal.cell(8, 2).unwrap().character, '1');
+/// assert_eq!(terminal.cell(7, 3).unwrap().character, '6');
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Hash, Debug)]
+pub struct Table<'a, R> {
+    /// The columns of the table.
+    pub columns: &'a [Column<'a>],
+    /// The rows of the table.
+    pub rows: &'a [R],
+    /// The text drawn between each pair of columns.
+    pub separator: &'a str,
+    /// The character that the line between the header and the rows is drawn with, if any.
+    pub header_separator: Option<char>,
+    /// What to do with cells that don't fit in their column.
+    pub overflow: Overflow,
+    /// The style to use for the header row and the line below it.
+    pub header_style: Style,
+    /// The style to use for the selected row.
+    pub selected_row_style: Style,
+    /// The style to use for the unselected rows.
+    pub unselected_row_style: Style,
+    /// The index of the currently selected row.
+    pub selected_row: Option<usize>,
+    /// The index of the row that was activated during the last update, if any.
+    pub activated_row: Option<usize>,
+    /// The index of the row at the top of the table, below the header.
+    scroll: usize,
+}
+
+impl<'a, R: AsRef<[&'a str]>> Table<'a, R> {
+    /// Create a new [`Table`] with a single space between columns, no line below the header, and
+    /// nothing selected. Cells that don't fit are cut off with an ellipsis.
+    ///
+    /// The selected row is drawn inverted by default.
+    #[must_use]
+    pub const fn new(columns: &'a [Column<'a>], rows: &'a [R]) -> Self {
+        Self {
+            columns,
+            rows,
+            separator: " ",
+            header_separator: None,
+            overflow: Overflow::Ellipsis,
+            header_style: Style::new(),
+            selected_row_style: Style::new().inverted(),
+            unselected_row_style: Style::new(),
+            selected_row: None,
+            activated_row: None,
+            scroll: 0,
+        }
+    }
+
+    /// Set the text drawn between columns, and the character that the line below the header is
+    /// drawn with.
+    #[must_use]
+    pub const fn with_separators(mut self, separator: &'a str, header_separator: Option<char>) -> Self {
+        self.separator = separator;
+        self.header_separator = header_separator;
+
+        self
+    }
+
+    /// Set the [`Overflow`] policy of the cells.
+    #[must_use]
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Select a row based on its order from top-to-bottom.
+    #[must_use]
+    pub const fn select(mut self, selection: usize) -> Self {
+        if selection < self.rows.len() {
+            self.selected_row = Some(selection);
+        }
+
+        self
+    }
+
+    /// Returns the currently selected row. Will be `None` if no row is selected.
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected_row
+    }
+
+    /// Returns the row that was activated (by pressing Enter or Space, or by clicking on it) during
+    /// the last call to [`Widget::update`]. Will be `None` if no row was activated.
+    #[must_use]
+    pub const fn activated(&self) -> Option<usize> {
+        self.activated_row
+    }
+
+    /// Moves the selection down by `rows` rows, stopping at the last row. Selects the first row if
+    /// nothing is selected.
+    const fn select_next(&mut self, rows: usize) {
+        let last = self.rows.len().saturating_sub(1);
+
+        self.selected_row = Some(match self.selected_row {
+            None => 0,
+            Some(selected) => {
+                let next = selected.saturating_add(rows);
+
+                if next > last { last } else { next }
+            }
+        });
+    }
+
+    /// Moves the selection up by `rows` rows, stopping at the first row. Selects the last row if
+    /// nothing is selected.
+    const fn select_previous(&mut self, rows: usize) {
+        self.selected_row = Some(match self.selected_row {
+            None => self.rows.len().saturating_sub(1),
+            Some(selected) => selected.saturating_sub(rows),
+        });
+    }
+
+    /// Returns the number of rows taken up by the header and the line below it.
+    const fn header_height(&self) -> usize {
+        if self.header_separator.is_some() { 2 } else { 1 }
+    }
+
+    /// Returns the text of a cell, or an empty string if the row doesn't have a cell for the column.
+    fn cell_text(row: &'a R, column: usize) -> &'a str {
+        row.as_ref().get(column).copied().unwrap_or_default()
+    }
+
+    /// Returns the width of the widest cell in a column, including its header.
+    fn content_width(&self, column_idx: usize) -> usize {
+        self.rows
+            .iter()
+            .map(|row| width::of_str(Self::cell_text(row, column_idx)))
+            .fold(width::of_str(self.columns[column_idx].header), usize::max)
+    }
+
+    /// Returns the widths of each column when the table is drawn `table_width` cells wide, before
+    /// they're cut off at the right edge.
+    ///
+    /// [`ColumnWidth::Fit`] columns share whatever space the other columns leave over, so a single
+    /// long cell can't push every other column off the edge.
+    fn column_widths(&self, table_width: usize) -> impl Iterator<Item = usize> + use<'_, 'a, R> {
+        let separators = width::of_str(self.separator) * self.columns.len().saturating_sub(1);
+        let available = table_width.saturating_sub(separators);
+
+        let sized_width = move |column: &Column| match column.width {
+            ColumnWidth::Fixed(width) => width,
+            ColumnWidth::Percentage(percentage) => available * percentage / 100,
+            ColumnWidth::Fit => 0,
+        };
+
+        let leftover = available.saturating_sub(self.columns.iter().map(sized_width).sum());
+        let fit_columns = self.columns.iter().filter(|column| column.width == ColumnWidth::Fit).count();
+        let fit_content: usize = (0..self.columns.len())
+            .filter(|&column_idx| self.columns[column_idx].width == ColumnWidth::Fit)
+            .map(|column_idx| self.content_width(column_idx))
+            .sum();
+        let fit_limit = if fit_content > leftover { leftover / fit_columns.max(1) } else { usize::MAX };
+
+        self.columns.iter().enumerate().map(move |(column_idx, column)| match column.width {
+            ColumnWidth::Fit => self.content_width(column_idx).min(fit_limit),
+            _ => sized_width(column),
+        })
+    }
+
+    /// Lays the columns out left-to-right, yielding each column's index, x-coordinate and width
+    /// after being cut off at the right edge. Columns that are entirely cut off are skipped.
+    fn layout(&self, table_width: usize) -> impl Iterator<Item = (usize, usize, usize)> + use<'_, 'a, R> {
+        let separator_width = width::of_str(self.separator);
+        let mut x = 0;
+
+        self.column_widths(table_width).enumerate().map_while(move |(column_idx, width)| {
+            let column_x = x;
+            let width = width.min(table_width.checked_sub(column_x)?);
+
+            x = column_x + width + separator_width;
+
+            Some((column_idx, column_x, width))
+        })
+    }
+
+    /// Returns the index of the row that needs to be at the top of the table to keep the selected
+    /// row visible when `rows` rows fit below the header.
+    fn scroll_for(&self, rows: usize) -> usize {
+        let Some(selected) = self.selected_row else {
+            return self.scroll.min(self.rows.len().saturating_sub(rows));
+        };
+
+        if selected < self.scroll {
+            selected
+        } else if selected >= self.scroll + rows {
+            (selected + 1).saturating_sub(rows)
+        } else {
+            self.scroll
+        }
+    }
+
+    /// Draws a single row of the table, made up of `cells`, at the given y-coordinate.
+    fn draw_row(&self, terminal: &mut impl Terminal, y: usize, cells: impl Fn(usize) -> &'a str, style: Style) -> crate::Result<()> {
+        let table_width = terminal.width();
+
+        for (column_idx, x, width) in self.layout(table_width) {
+            let cell = Rectangle::new((x, y), (x + width, y + 1));
+            draw_cell(terminal, cell, cells(column_idx), self.columns[column_idx].alignment, self.overflow, style)?;
+
+            let separator_end = (x + width + width::of_str(self.separator)).min(table_width);
+
+            if column_idx + 1 < self.columns.len() && separator_end > x + width {
+                let separator = Rectangle::new((x + width, y), (separator_end, y + 1));
+                draw_cell(terminal, separator, self.separator, Alignment::Left, Overflow::Clip, style)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Draws `text` into a single-row `area` of the terminal, filling the rest of the area with spaces.
+fn draw_cell(terminal: &mut impl Terminal, area: Rectangle, text: &str, alignment: Alignment, overflow: Overflow, style: Style) -> crate::Result<()> {
+    let width = area.width();
+
+    if width == 0 {
+        return Ok(());
+    }
+
+    let text_width = width::of_str(text);
+    let ellipsis = text_width > width && overflow == Overflow::Ellipsis;
+    let limit = if ellipsis { width - 1 } else { width };
+    let padding = match alignment {
+        _ if text_width >= width => 0,
+        Alignment::Left | Alignment::Justified => 0,
+        Alignment::Center => (width - text_width) / 2,
+        Alignment::Right => width - text_width,
+    };
+
+    let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+    let mut writer = CellWriter::new(view.cells_mut(), width);
+    let mut written = 0;
+
+    for _ in 0..padding {
+        writer.write(' ', style);
+    }
+
+    for character in text.chars() {
+        written += width::of_char(character);
+
+        if written > limit {
+            break;
+        }
+
+        writer.write(character, style);
+    }
+
+    if ellipsis {
+        // Skip over the cell that a wide character didn't fit in, so the ellipsis is at the end.
+        while writer.column() < limit && writer.write(' ', style).is_some() {}
+
+        writer.write('…', style);
+    }
+
+    for _ in 0..width {
+        if writer.write(' ', style).is_none() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a, R: AsRef<[&'a str]>> Widget for Table<'a, R> {
+    fn update(
+        &mut self,
+        update_info: UpdateInfo,
+        terminal: impl TerminalConst,
+    ) -> crate::Result<UpdateResult> {
+        self.activated_row = None;
+
+        if self.rows.is_empty() {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        let rows = terminal.height().saturating_sub(self.header_height()).max(1);
+        let last = self.rows.len() - 1;
+        let previous_selection = self.selected_row;
+
+        match update_info {
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => match key {
+                Key::Down => self.select_next(1),
+                Key::Up => self.select_previous(1),
+                Key::PageDown => self.select_next(rows),
+                Key::PageUp => self.select_previous(rows),
+                Key::Home => self.selected_row = Some(0),
+                Key::End => self.selected_row = Some(last),
+                Key::Enter => self.activated_row = self.selected_row,
+                _ => {}
+            },
+            UpdateInfo::KeyboardCharacter(' ', KeyState::KeyDown) => self.activated_row = self.selected_row,
+            UpdateInfo::MouseScrolled(_x, _y, ScrollDirection::Down) => self.select_next(1),
+            UpdateInfo::MouseScrolled(_x, _y, ScrollDirection::Up) => self.select_previous(1),
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) if x < terminal.width() && y < terminal.height() => {
+                let clicked = y.checked_sub(self.header_height()).map(|row| self.scroll + row);
+
+                if let Some(clicked) = clicked.filter(|&clicked| clicked <= last) {
+                    self.selected_row = Some(clicked);
+                    self.activated_row = Some(clicked);
+                }
+            }
+            _ => {}
+        }
+
+        self.scroll = self.scroll_for(rows);
+
+        if self.activated_row.is_some() || self.selected_row != previous_selection {
+            Ok(UpdateResult::Interacted)
+        } else {
+            Ok(UpdateResult::NoEvent)
+        }
+    }
+
+    fn draw(
+        &self,
+        mut terminal: impl Terminal,
+    ) -> crate::Result<UpdateResult> {
+        let (table_width, height) = terminal.dimensions();
+
+        if height == 0 {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        self.draw_row(&mut terminal, 0, |column_idx| self.columns[column_idx].header, self.header_style)?;
+
+        if let (Some(line), true) = (self.header_separator, height > 1) {
+            let area = Rectangle::new((0, 1), (table_width, 2));
+            let mut view = terminal.view_mut(area).ok_or_else(|| Error::oob_with(area.right_bottom()))?;
+            let mut writer = CellWriter::new(view.cells_mut(), table_width);
+            // Zero-width lines and lines that are wider than the table can't be drawn, so the row is left blank.
+            let line_width = width::of_char(line);
+            let lines = table_width.checked_div(line_width).unwrap_or(0);
+
+            for _ in 0..lines {
+                writer.write(line, self.header_style);
+            }
+
+            for _ in lines * line_width..table_width {
+                writer.write(' ', self.header_style);
+            }
+        }
+
+        let rows = height.saturating_sub(self.header_height());
+        let scroll = self.scroll_for(rows);
+        let unselected_row_style = self.unselected_row_style.inherits(self.selected_row_style.reset());
+
+        for (y, row_idx) in (scroll..self.rows.len()).take(rows).enumerate() {
+            let style = if Some(row_idx) == self.selected_row { self.selected_row_style } else { unselected_row_style };
+            let row = &self.rows[row_idx];
+
+            self.draw_row(&mut terminal, self.header_height() + y, |column_idx| Self::cell_text(row, column_idx), style)?;
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn focusable(&self) -> usize {
+        usize::from(!self.rows.is_empty())
+    }
+}
+
+impl<'a, R: AsRef<[&'a str]>> BoundingBox for Table<'a, R> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        let width = self
+            .layout(rect.width())
+            .last()
+            .map_or(0, |(_column_idx, x, width)| x + width);
+        let height = self.header_height() + self.rows.len();
+
+        Ok(Rectangle::of_size((width, height.min(rect.height()))))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        // The separators between columns and the line below the header cover the gaps between cells.
+        self.header_height() + self.rows.len() >= rectangle.height()
+            && self.bounding_box(rectangle).is_ok_and(|rect| rect.width() >= rectangle.width())
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::{Column, Table};
+    use crate::prelude::*;
+    use crate::terminal::{Cell, ConstantSize};
+
+    const COLUMNS: [Column; 1] = [Column::new("a")];
+    const ROWS: [[&str; 1]; 1] = [["b"]];
+
+    #[test]
+    fn wide_header_separator() {
+        let mut terminal: ConstantSize<1, 3> = ConstantSize::new();
+
+        Table::new(&COLUMNS, &ROWS).with_separators(" ", Some('＝')).drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters.map(|row| row[0].character), ['a', ' ', 'b']);
+
+        let mut terminal: ConstantSize<3, 2> = ConstantSize::new();
+
+        Table::new(&COLUMNS, &ROWS).with_separators(" ", Some('＝')).drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters[1].map(|cell| cell.character), ['＝', Cell::CONTINUATION, ' ']);
+    }
+
+    #[test]
+    fn zero_width_header_separator() {
+        let mut terminal: ConstantSize<3, 3> = ConstantSize::new();
+
+        Table::new(&COLUMNS, &ROWS).with_separators(" ", Some('\u{200B}')).drawn(&mut terminal).expect("Should draw successfully");
+
+        assert_eq!(terminal.characters[1].map(|cell| cell.character), [' '; 3]);
+    }
+}
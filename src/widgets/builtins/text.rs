@@ -2,6 +2,7 @@ use crate::prelude::Terminal;
 use crate::prelude::TerminalConst;
 use crate::prelude::Widget;
 use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
 use crate::terminal::{Rectangle, UpdateInfo, UpdateResult};
 use crate::widgets::BoundingBox;
 use crate::Error;
@@ -46,6 +47,24 @@ impl<'a> Text<'a> {
     /// text_widget.drawn(&mut tremendous_terminal).expect("There is enough space");
     /// text_widget.drawn(&mut tiny_terminal).expect_err("There is not enough space, so we get an `Err`.");
     /// ```
+    ///
+    /// ## Wide characters
+    ///
+    /// Wide characters take up two cells, and are never split across two rows.
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::widgets::builtins::Text;
+    /// use tuit::terminal::ConstantSize;
+    ///
+    /// let mut terminal: ConstantSize<3, 2> = ConstantSize::new();
+    ///
+    /// Text::new("日本").drawn(&mut terminal).expect("There is enough space");
+    ///
+    /// assert_eq!(terminal.cell(0, 0).unwrap().character, '日');
+    /// assert!(terminal.cell(1, 0).unwrap().is_continuation());
+    /// assert_eq!(terminal.cell(0, 1).unwrap().character, '本');
+    /// ```
     #[must_use]
     pub const fn new(text: &'a str) -> Self {
         Self {
@@ -87,13 +106,11 @@ impl Widget for Text<'_> {
         &self,
         mut terminal: impl Terminal,
     ) -> crate::Result<UpdateResult> {
-        let mut cells = terminal.cells_mut();
+        let width = terminal.width();
+        let mut writer = CellWriter::new(terminal.cells_mut(), width);
 
         for (idx, character) in self.text.chars().enumerate() {
-            let current_cell = cells.next().ok_or(Error::OutOfBoundsIndex(idx))?;
-
-            current_cell.character = character;
-            current_cell.style = self.style.inherits(current_cell.style);
+            writer.write(character, self.style).ok_or(Error::OutOfBoundsIndex(idx))?;
         }
 
         Ok(UpdateResult::NoEvent)
@@ -102,13 +119,12 @@ impl Widget for Text<'_> {
 
 impl BoundingBox for Text<'_> {
     fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
-        let height = self.text.len().div_ceil(rect.width()).min(rect.height());
-        let width = self.text.len().min(rect.width());
+        let (width, height) = width::wrapped_dimensions(self.text, rect.width());
 
-        Ok(Rectangle::of_size((width, height)))
+        Ok(Rectangle::of_size((width, height.min(rect.height()))))
     }
 
     fn completely_covers(&self, rectangle: Rectangle) -> bool {
-        self.text.len() >= rectangle.area()
+        width::of_str(self.text) >= rectangle.area()
     }
 }
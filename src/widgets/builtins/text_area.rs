@@ -0,0 +1,546 @@
+use core::str::CharIndices;
+
+use crate::style::Style;
+use crate::terminal::width;
+use crate::terminal::{Cell, Key, KeyState, Modifiers, MouseButton, Rectangle, Terminal, TerminalConst, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::text_buffer::TextBuffer;
+use crate::widgets::{BoundingBox, Widget};
+
+/// Where a character of the text is displayed, before scrolling.
+#[derive(Copy, Clone, Debug)]
+struct Placement {
+    /// The byte index of the character.
+    index: usize,
+    /// The character, or `None` for the end of the text.
+    character: Option<char>,
+    row: usize,
+    column: usize,
+    width: usize,
+}
+
+/// Places the characters of some text on screen, followed by the end of the text.
+///
+/// Newlines and the end of the text take up one column, because the cursor can be displayed there.
+/// Newlines never wrap, so one that ends a full row is placed just past the row's last column.
+struct Layout<'a> {
+    chars: CharIndices<'a>,
+    len: usize,
+    /// The width of the rows when wrapping is enabled.
+    row_width: Option<usize>,
+    row: usize,
+    column: usize,
+    done: bool,
+}
+
+impl<'a> Layout<'a> {
+    fn new(text: &'a str, row_width: Option<usize>) -> Self {
+        Self {
+            chars: text.char_indices(),
+            len: text.len(),
+            row_width,
+            row: 0,
+            column: 0,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Layout<'_> {
+    type Item = Placement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let (index, character) = self.chars.next().map_or_else(|| {
+            self.done = true;
+
+            (self.len, None)
+        }, |(index, character)| (index, Some(character)));
+
+        let width = match character {
+            Some('\n') | None => 1,
+            Some(character) => width::of_char(character),
+        };
+
+        if let Some(row_width) = self.row_width.filter(|_| character != Some('\n')) {
+            if self.column > 0 && self.column + width > row_width {
+                self.row += 1;
+                self.column = 0;
+            }
+        }
+
+        let placement = Placement { index, character, row: self.row, column: self.column, width };
+
+        if character == Some('\n') {
+            self.row += 1;
+            self.column = 0;
+        } else {
+            self.column += width;
+        }
+
+        Some(placement)
+    }
+}
+
+/// A multi-line text editor.
+///
+/// The text is stored in a [`TextBuffer`]. Use a [`SliceBuffer`](super::SliceBuffer) to store it in
+/// a fixed-capacity slice without allocating, or a [`String`](alloc::string::String) with the
+/// `alloc` feature enabled to let it grow as needed.
+///
+/// The [`TextArea`] fills the terminal, and scrolls to keep the cursor in view. When wrapping is
+/// enabled, lines that are too long to fit get continued on the next row; otherwise, the text
+/// scrolls horizontally.
+///
+/// It reacts to these events:
+/// - Typed characters get inserted at the cursor, and Enter starts a new line.
+/// - The arrow keys move the cursor, and Page Up and Page Down move it by a whole screen.
+/// - Home and End move the cursor to the start and end of the line.
+/// - Backspace and Delete remove the character before and after the cursor, joining lines together
+///   when a line break gets removed.
+/// - Clicking on the text moves the cursor to the clicked character.
+///
+/// [`Widget::update`] returns [`UpdateResult::Interacted`] when the [`TextArea`] changes in a way
+/// that requires it to be redrawn.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Key, KeyState, UpdateInfo};
+/// use tuit::widgets::builtins::{SliceBuffer, TextArea};
+///
+/// let mut terminal: ConstantSize<20, 5> = ConstantSize::new();
+/// let mut bytes = [0; 64];
+/// let mut editor = TextArea::new(SliceBuffer::new(&mut bytes));
+///
+/// let mut type_key = |update_info| editor.update(update_info, &terminal).unwrap();
+///
+/// for character in "ab".chars() {
+///     type_key(UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown));
+/// }
+///
+/// type_key(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown));
+/// type_key(UpdateInfo::KeyboardCharacter('c', KeyState::KeyDown));
+///
+/// assert_eq!(editor.text(), "ab\nc");
+/// assert_eq!(editor.cursor_position(), (1, 1));
+///
+/// // Backspacing at the start of a line joins it with the previous one.
+/// let mut type_key = |update_info| editor.update(update_info, &terminal).unwrap();
+///
+/// type_key(UpdateInfo::KeyboardInput(Key::Home, KeyState::KeyDown));
+/// type_key(UpdateInfo::KeyboardInput(Key::Backspace, KeyState::KeyDown));
+///
+/// assert_eq!(editor.text(), "abc");
+///
+/// editor.drawn(&mut terminal).unwrap();
+///
+/// assert_eq!(terminal.cell(2, 0).unwrap().character, 'c');
+/// ```
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct TextArea<B> {
+    buffer: B,
+    cursor: usize,
+    /// The (column, row) that the top-left of the terminal is scrolled to.
+    scroll: (usize, usize),
+    /// Whether lines that are too long to fit get continued on the next row.
+    pub wrap: bool,
+    /// The style of the text.
+    pub style: Style,
+    /// The style of the cell under the cursor, which inherits from [`TextArea::style`]. Properties
+    /// that it sets are reset to their defaults everywhere else (see [`Style::reset`]).
+    pub cursor_style: Style,
+}
+
+impl<B: TextBuffer> TextArea<B> {
+    /// Create a new [`TextArea`] that edits the text in `buffer`, with the cursor at the start.
+    ///
+    /// Wrapping is disabled, and the cursor is drawn inverted by default.
+    #[must_use]
+    pub const fn new(buffer: B) -> Self {
+        Self {
+            buffer,
+            cursor: 0,
+            scroll: (0, 0),
+            wrap: false,
+            style: Style::new(),
+            cursor_style: Style::new().inverted(),
+        }
+    }
+
+    /// Enable or disable wrapping.
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::ConstantSize;
+    /// use tuit::widgets::builtins::{SliceBuffer, TextArea, TextBuffer};
+    ///
+    /// let mut terminal: ConstantSize<4, 3> = ConstantSize::new();
+    /// let mut bytes = [0; 16];
+    /// let mut buffer = SliceBuffer::new(&mut bytes);
+    ///
+    /// buffer.insert_str(0, "abcdef").unwrap();
+    ///
+    /// TextArea::new(buffer).wrapped(true).drawn(&mut terminal).unwrap();
+    ///
+    /// assert_eq!(terminal.cell(0, 1).unwrap().character, 'e');
+    /// ```
+    #[must_use]
+    pub const fn wrapped(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+
+        self
+    }
+
+    /// Apply a [`Style`] to the text of the [`TextArea`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the [`Style`] of the cell under the cursor.
+    #[must_use]
+    pub const fn with_cursor_style(mut self, cursor_style: Style) -> Self {
+        self.cursor_style = cursor_style;
+
+        self
+    }
+
+    /// Returns the text of the [`TextArea`].
+    #[must_use]
+    pub fn text(&self) -> &str {
+        self.buffer.as_str()
+    }
+
+    /// Returns a reference to the [`TextBuffer`].
+    pub const fn buffer(&self) -> &B {
+        &self.buffer
+    }
+
+    /// Consume [`self`] and return the [`TextBuffer`].
+    pub fn into_inner(self) -> B {
+        self.buffer
+    }
+
+    /// Returns the byte index of the cursor within [`TextArea::text`].
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the line and column of the cursor, counting characters from zero.
+    #[must_use]
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.text()[..self.cursor];
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        (line, before[line_start..].chars().count())
+    }
+
+    /// Inserts a character at the cursor, and moves the cursor past it.
+    ///
+    /// Returns `None` if there isn't enough space left in the buffer.
+    pub fn insert(&mut self, character: char) -> Option<()> {
+        let mut encoded = [0; 4];
+        let encoded = character.encode_utf8(&mut encoded);
+
+        self.buffer.insert_str(self.cursor, encoded)?;
+        self.cursor += encoded.len();
+
+        Some(())
+    }
+
+    /// Removes the character before the cursor.
+    pub fn backspace(&mut self) {
+        let Some(previous) = self.text()[..self.cursor].chars().next_back() else {
+            return;
+        };
+
+        self.cursor -= previous.len_utf8();
+        self.buffer.remove(self.cursor..self.cursor + previous.len_utf8());
+    }
+
+    /// Removes the character after the cursor.
+    pub fn delete(&mut self) {
+        let Some(next) = self.text()[self.cursor..].chars().next() else {
+            return;
+        };
+
+        self.buffer.remove(self.cursor..self.cursor + next.len_utf8());
+    }
+
+    /// Moves the cursor one character to the left.
+    pub fn move_left(&mut self) {
+        if let Some(previous) = self.text()[..self.cursor].chars().next_back() {
+            self.cursor -= previous.len_utf8();
+        }
+    }
+
+    /// Moves the cursor one character to the right.
+    pub fn move_right(&mut self) {
+        if let Some(next) = self.text()[self.cursor..].chars().next() {
+            self.cursor += next.len_utf8();
+        }
+    }
+
+    /// Moves the cursor to the start of the line.
+    pub fn move_home(&mut self) {
+        self.cursor = self.text()[..self.cursor].rfind('\n').map_or(0, |newline| newline + 1);
+    }
+
+    /// Moves the cursor to the end of the line.
+    pub fn move_end(&mut self) {
+        let text = self.text();
+
+        self.cursor = text[self.cursor..].find('\n').map_or(text.len(), |newline| self.cursor + newline);
+    }
+
+    /// Returns the row width to lay the text out with, or `None` if wrapping is disabled.
+    fn row_width(&self, terminal_width: usize) -> Option<usize> {
+        self.wrap.then_some(terminal_width)
+    }
+
+    /// Returns where the character at `index` (or the end of the text) is placed.
+    fn placement_of(&self, row_width: Option<usize>, index: usize) -> Option<Placement> {
+        Layout::new(self.text(), row_width).find(|placement| placement.index == index)
+    }
+
+    /// Returns the index of the character displayed at (`column`, `row`), or of the last character
+    /// before it on the same row. Returns the end of the text if the row is past the end.
+    fn index_at(&self, row_width: Option<usize>, (column, row): (usize, usize)) -> usize {
+        let mut found = None;
+
+        for placement in Layout::new(self.text(), row_width) {
+            if placement.row > row {
+                break;
+            }
+
+            if placement.row == row && placement.width > 0 && (found.is_none() || placement.column <= column) {
+                found = Some(placement.index);
+            }
+        }
+
+        found.unwrap_or_else(|| self.text().len())
+    }
+
+    /// Moves the cursor up or down by `rows` rows, keeping it in the same column where possible.
+    fn move_rows(&mut self, row_width: Option<usize>, rows: usize, up: bool) {
+        let Some(placement) = self.placement_of(row_width, self.cursor) else {
+            return;
+        };
+
+        let row = if up {
+            let Some(row) = placement.row.checked_sub(rows) else {
+                self.cursor = 0;
+
+                return;
+            };
+
+            row
+        } else {
+            placement.row + rows
+        };
+
+        self.cursor = self.index_at(row_width, (placement.column, row));
+    }
+
+    /// Returns the (column, row) that the text needs to be scrolled to to keep the cursor visible in
+    /// a terminal of the given size.
+    fn scroll_for(&self, (width, height): (usize, usize)) -> (usize, usize) {
+        let row_width = self.row_width(width);
+        let Some(cursor) = self.placement_of(row_width, self.cursor) else {
+            return self.scroll;
+        };
+
+        let (mut column, mut row) = self.scroll;
+
+        if cursor.row < row {
+            row = cursor.row;
+        } else if cursor.row >= row + height {
+            row = (cursor.row + 1).saturating_sub(height);
+        }
+
+        if row_width.is_some() {
+            column = 0;
+        } else if cursor.column < column {
+            column = cursor.column;
+        } else if cursor.column + cursor.width > column + width {
+            column = (cursor.column + cursor.width).saturating_sub(width);
+        }
+
+        (column, row)
+    }
+}
+
+impl<B: TextBuffer> Widget for TextArea<B> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        let (width, height) = terminal.dimensions();
+        let row_width = self.row_width(width);
+        let previous = (self.text().len(), self.cursor);
+
+        match update_info {
+            UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown | KeyState::KeyHeld)
+                if character == '\n' || !character.is_control() => {
+                self.insert(character);
+            }
+            UpdateInfo::ModifiedKeyboardCharacter(character, modifiers, KeyState::KeyDown | KeyState::KeyHeld)
+                if !character.is_control() && modifiers.difference(Modifiers::SHIFT).is_empty() => {
+                self.insert(character);
+            }
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => match key {
+                Key::Left => self.move_left(),
+                Key::Right => self.move_right(),
+                Key::Up => self.move_rows(row_width, 1, true),
+                Key::Down => self.move_rows(row_width, 1, false),
+                Key::PageUp => self.move_rows(row_width, height.max(1), true),
+                Key::PageDown => self.move_rows(row_width, height.max(1), false),
+                Key::Home => self.move_home(),
+                Key::End => self.move_end(),
+                Key::Backspace => self.backspace(),
+                Key::Delete => self.delete(),
+                Key::Enter => {
+                    self.insert('\n');
+                }
+                _ => {}
+            },
+            UpdateInfo::CellClicked(x, y, MouseButton::Primary) if x < width && y < height => {
+                let (column, row) = self.scroll;
+
+                self.cursor = self.index_at(row_width, (column + x, row + y));
+            }
+            _ => {}
+        }
+
+        let previous_scroll = self.scroll;
+        self.scroll = self.scroll_for((width, height));
+
+        if (self.text().len(), self.cursor) != previous || self.scroll != previous_scroll {
+            Ok(UpdateResult::Interacted)
+        } else {
+            Ok(UpdateResult::NoEvent)
+        }
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let (width, height) = terminal.dimensions();
+        let (scroll_column, scroll_row) = self.scroll_for((width, height));
+        let text_style = self.style.inherits(self.cursor_style.reset());
+        let cursor_style = self.cursor_style.inherits(self.style);
+
+        for cell in terminal.cells_mut() {
+            cell.character = ' ';
+            cell.style = text_style.inherits(cell.style);
+        }
+
+        for placement in Layout::new(self.text(), self.row_width(width)) {
+            if placement.row < scroll_row || placement.width == 0 {
+                continue;
+            }
+
+            if placement.row >= scroll_row + height {
+                break;
+            }
+
+            // Characters that are cut off by the left or right edge aren't drawn.
+            if placement.column < scroll_column || placement.column + placement.width > scroll_column + width {
+                // A newline that ends a full row is just past the right edge, so the cursor is shown
+                // on the row's last cell instead.
+                if placement.index == self.cursor && placement.column == scroll_column + width {
+                    if let Some(cell) = width.checked_sub(1).and_then(|x| terminal.cell_mut(x, placement.row - scroll_row)) {
+                        cell.style = cursor_style.inherits(cell.style);
+                    }
+                }
+
+                continue;
+            }
+
+            let (x, y) = (placement.column - scroll_column, placement.row - scroll_row);
+            let style = if placement.index == self.cursor { cursor_style } else { text_style };
+            let character = placement.character.filter(|&character| character != '\n').unwrap_or(' ');
+
+            for offset in 0..placement.width {
+                let Some(cell) = terminal.cell_mut(x + offset, y) else {
+                    break;
+                };
+
+                cell.character = if offset == 0 { character } else { Cell::CONTINUATION };
+                cell.style = style.inherits(cell.style);
+            }
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn focusable(&self) -> usize {
+        1
+    }
+}
+
+impl<B: TextBuffer> BoundingBox for TextArea<B> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        Ok(Rectangle::of_size(rect.dimensions()))
+    }
+
+    fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+        true
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::TextArea;
+    use crate::prelude::*;
+    use crate::terminal::{ConstantSize, Key, KeyState, UpdateInfo};
+    use crate::widgets::builtins::{SliceBuffer, TextBuffer};
+
+    fn rows<const WIDTH: usize, const HEIGHT: usize>(text: &str) -> Vec<String> {
+        let mut terminal: ConstantSize<WIDTH, HEIGHT> = ConstantSize::new();
+        let mut bytes = [0; 32];
+        let mut buffer = SliceBuffer::new(&mut bytes);
+
+        buffer.insert_str(0, text).expect("Text should fit in the buffer");
+
+        TextArea::new(buffer).wrapped(true).drawn(&mut terminal).expect("Should draw successfully");
+
+        terminal.characters.iter().map(|row| row.iter().map(|cell| cell.character).collect()).collect()
+    }
+
+    #[test]
+    fn newline_after_full_row() {
+        assert_eq!(rows::<4, 3>("abcd\nef"), ["abcd", "ef  ", "    "]);
+    }
+
+    #[test]
+    fn exact_width_rows() {
+        assert_eq!(rows::<4, 3>("abcdefgh"), ["abcd", "efgh", "    "]);
+        assert_eq!(rows::<4, 3>("abcd\n\nef"), ["abcd", "    ", "ef  "]);
+    }
+
+    #[test]
+    fn cursor_on_newline_after_full_row() {
+        let mut terminal: ConstantSize<4, 3> = ConstantSize::new();
+        let mut bytes = [0; 32];
+        let mut buffer = SliceBuffer::new(&mut bytes);
+
+        buffer.insert_str(0, "abcd\nef").expect("Text should fit in the buffer");
+
+        let mut editor = TextArea::new(buffer).wrapped(true);
+
+        editor.update(UpdateInfo::KeyboardInput(Key::End, KeyState::KeyDown), &terminal).expect("Should update successfully");
+        editor.drawn(&mut terminal).expect("Should draw successfully");
+
+        let cell = terminal.cell(3, 0).expect("Cell should exist");
+
+        assert_eq!(cell.character, 'd');
+        assert_eq!(cell.style.invert, Some(true));
+    }
+}
@@ -0,0 +1,109 @@
+use core::ops::Range;
+
+/// Storage for the text of an editable widget, like the [`TextArea`](super::TextArea).
+///
+/// Tuit provides [`SliceBuffer`], which stores text in a fixed-capacity byte slice so that it works
+/// without allocation. With the `alloc` feature enabled, [`String`](alloc::string::String) is a
+/// [`TextBuffer`] that grows as needed.
+///
+/// Indices are byte indices into [`TextBuffer::as_str`], and are always on character boundaries.
+pub trait TextBuffer {
+    /// Returns the text in the buffer.
+    fn as_str(&self) -> &str;
+
+    /// Inserts `text` at the byte index `index`.
+    ///
+    /// Returns `None` without modifying the buffer if there isn't enough space for all of `text`.
+    fn insert_str(&mut self, index: usize, text: &str) -> Option<()>;
+
+    /// Removes the text in the byte range `range`.
+    fn remove(&mut self, range: Range<usize>);
+
+    /// Removes all the text in the buffer.
+    fn clear(&mut self) {
+        let len = self.as_str().len();
+
+        self.remove(0..len);
+    }
+}
+
+/// A [`TextBuffer`] that stores UTF-8 text in a caller-provided byte slice, so it never allocates.
+///
+/// ```
+/// use tuit::widgets::builtins::{SliceBuffer, TextBuffer};
+///
+/// let mut bytes = [0; 8];
+/// let mut buffer = SliceBuffer::new(&mut bytes);
+///
+/// buffer.insert_str(0, "world").expect("There is enough space");
+/// buffer.insert_str(0, "hi ").expect("There is enough space");
+///
+/// assert_eq!(buffer.as_str(), "hi world");
+/// // The slice is full, so nothing else can be inserted.
+/// assert_eq!(buffer.insert_str(0, "!"), None);
+///
+/// buffer.remove(0..3);
+///
+/// assert_eq!(buffer.as_str(), "world");
+/// ```
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct SliceBuffer<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceBuffer<'a> {
+    /// Create a new, empty [`SliceBuffer`]. The previous contents of `bytes` are ignored.
+    #[must_use]
+    pub const fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, len: 0 }
+    }
+
+    /// Returns the maximum length of the text in bytes.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the length of the text in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the buffer holds no text.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl TextBuffer for SliceBuffer<'_> {
+    fn as_str(&self) -> &str {
+        // The buffer only ever has whole characters written into it.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    fn insert_str(&mut self, index: usize, text: &str) -> Option<()> {
+        let end = self.len + text.len();
+
+        if end > self.bytes.len() || index > self.len {
+            return None;
+        }
+
+        self.bytes.copy_within(index..self.len, index + text.len());
+        self.bytes[index..index + text.len()].copy_from_slice(text.as_bytes());
+        self.len = end;
+
+        Some(())
+    }
+
+    fn remove(&mut self, range: Range<usize>) {
+        self.bytes.copy_within(range.end..self.len, range.start);
+        self.len -= range.len();
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
@@ -0,0 +1,455 @@
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Key, KeyState, Modifiers, MouseButton, Rectangle, Terminal, TerminalConst, TerminalMut, UpdateInfo, UpdateResult};
+use crate::widgets::builtins::text_buffer::{SliceBuffer, TextBuffer};
+use crate::widgets::{BoundingBox, Widget};
+use crate::Error;
+
+/// A single-line text input field.
+///
+/// The text is stored as UTF-8 in a buffer that you provide, so the [`TextInput`] never allocates.
+/// Characters that don't fit in the buffer are ignored.
+///
+/// The [`TextInput`] takes up the entire first row of the terminal, and scrolls horizontally to keep
+/// the cursor in view when the text is wider than the row.
+///
+/// It reacts to these events:
+/// - Typed characters get inserted at the cursor.
+/// - Left and Right move the cursor by one character, and Home and End move it to either end.
+/// - Backspace and Delete remove the character before and after the cursor.
+/// - Clicking on the text moves the cursor to the clicked character.
+/// - Enter submits the text, see [`TextInput::submitted`].
+///
+/// [`Widget::update`] returns [`UpdateResult::Interacted`] when the text is submitted or when the
+/// [`TextInput`] changes in a way that requires it to be redrawn.
+///
+/// ```
+/// use tuit::prelude::*;
+/// use tuit::terminal::{ConstantSize, Key, KeyState, UpdateInfo, UpdateResult};
+/// use tuit::widgets::builtins::TextInput;
+///
+/// let terminal: ConstantSize<20, 1> = ConstantSize::new();
+/// let mut buffer = [0; 32];
+/// let mut input = TextInput::new(&mut buffer);
+///
+/// for character in "Hello!".chars() {
+///     input.update(UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown), &terminal).unwrap();
+/// }
+///
+/// input.update(UpdateInfo::KeyboardInput(Key::Backspace, KeyState::KeyDown), &terminal).unwrap();
+///
+/// assert_eq!(input.text(), "Hello");
+/// assert!(!input.submitted());
+///
+/// let result = input.update(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown), &terminal).unwrap();
+///
+/// assert_eq!(result, UpdateResult::Interacted);
+/// assert!(input.submitted());
+/// ```
+#[derive(Eq, PartialEq, Hash, Debug)]
+pub struct TextInput<'a> {
+    buffer: SliceBuffer<'a>,
+    cursor: usize,
+    scroll: usize,
+    /// The style of the text.
+    pub style: Style,
+    /// The style of the cell under the cursor, which inherits from [`TextInput::style`]. Properties
+    /// that it sets are reset to their defaults everywhere else (see [`Style::reset`]).
+    pub cursor_style: Style,
+    /// A character to display in place of every character of the text, for passwords and the like.
+    pub mask: Option<char>,
+    /// Whether Enter was pressed during the last update.
+    pub submitted: bool,
+}
+
+impl<'a> TextInput<'a> {
+    /// Create a new, empty [`TextInput`] that stores its text in `buffer`. The previous contents of
+    /// `buffer` are ignored.
+    ///
+    /// The cursor is drawn inverted by default.
+    #[must_use]
+    pub const fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer: SliceBuffer::new(buffer),
+            cursor: 0,
+            scroll: 0,
+            style: Style::new(),
+            cursor_style: Style::new().inverted(),
+            mask: None,
+            submitted: false,
+        }
+    }
+
+    /// Apply a [`Style`] to the text of the [`TextInput`].
+    #[must_use]
+    pub const fn styled(mut self, style: Style) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the [`Style`] of the cell under the cursor.
+    #[must_use]
+    pub const fn with_cursor_style(mut self, cursor_style: Style) -> Self {
+        self.cursor_style = cursor_style;
+
+        self
+    }
+
+    /// Display every character as `mask` instead of the character itself.
+    ///
+    /// ```
+    /// use tuit::prelude::*;
+    /// use tuit::terminal::ConstantSize;
+    /// use tuit::widgets::builtins::TextInput;
+    ///
+    /// let mut terminal: ConstantSize<10, 1> = ConstantSize::new();
+    /// let mut buffer = [0; 16];
+    /// let mut password = TextInput::new(&mut buffer).masked('*');
+    ///
+    /// password.set_text("hunter2").expect("The buffer is big enough");
+    /// password.drawn(&mut terminal).unwrap();
+    ///
+    /// assert_eq!(terminal.cell(0, 0).unwrap().character, '*');
+    /// assert_eq!(terminal.cell(6, 0).unwrap().character, '*');
+    /// assert_eq!(password.text(), "hunter2");
+    /// ```
+    #[must_use]
+    pub const fn masked(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+
+        self
+    }
+
+    /// Returns the text of the [`TextInput`].
+    #[must_use]
+    pub fn text(&self) -> &str {
+        self.buffer.as_str()
+    }
+
+    /// Returns the maximum length of the text in bytes.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// Returns the byte index of the cursor within [`TextInput::text`].
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns whether Enter was pressed during the last call to [`Widget::update`].
+    #[must_use]
+    pub const fn submitted(&self) -> bool {
+        self.submitted
+    }
+
+    /// Replaces the text of the [`TextInput`], and moves the cursor to the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBoundsIndex`] if the text doesn't fit in the buffer. The text is left
+    /// unchanged if this happens.
+    pub fn set_text(&mut self, text: &str) -> crate::Result<()> {
+        if text.len() > self.buffer.capacity() {
+            return Err(Error::OutOfBoundsIndex(text.len()));
+        }
+
+        self.buffer.clear();
+        self.buffer.insert_str(0, text).ok_or(Error::OutOfBoundsIndex(text.len()))?;
+        self.cursor = text.len();
+
+        Ok(())
+    }
+
+    /// Removes all the text from the [`TextInput`].
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.cursor = 0;
+        self.scroll = 0;
+    }
+
+    /// Inserts a character at the cursor, and moves the cursor past it.
+    ///
+    /// Returns `None` if there isn't enough space left in the buffer.
+    pub fn insert(&mut self, character: char) -> Option<()> {
+        let mut encoded = [0; 4];
+        let encoded = character.encode_utf8(&mut encoded);
+
+        self.buffer.insert_str(self.cursor, encoded)?;
+        self.cursor += encoded.len();
+
+        Some(())
+    }
+
+    /// Removes the character before the cursor.
+    pub fn backspace(&mut self) {
+        let Some(previous) = self.text()[..self.cursor].chars().next_back() else {
+            return;
+        };
+
+        self.cursor -= previous.len_utf8();
+        self.remove_at_cursor(previous.len_utf8());
+    }
+
+    /// Removes the character after the cursor.
+    pub fn delete(&mut self) {
+        let Some(next) = self.text()[self.cursor..].chars().next() else {
+            return;
+        };
+
+        self.remove_at_cursor(next.len_utf8());
+    }
+
+    /// Moves the cursor one character to the left.
+    pub fn move_left(&mut self) {
+        if let Some(previous) = self.text()[..self.cursor].chars().next_back() {
+            self.cursor -= previous.len_utf8();
+        }
+    }
+
+    /// Moves the cursor one character to the right.
+    pub fn move_right(&mut self) {
+        if let Some(next) = self.text()[self.cursor..].chars().next() {
+            self.cursor += next.len_utf8();
+        }
+    }
+
+    /// Moves the cursor to the start of the text.
+    pub const fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the text.
+    pub const fn move_end(&mut self) {
+        self.cursor = self.buffer.len();
+    }
+
+    /// Removes `len` bytes after the cursor.
+    fn remove_at_cursor(&mut self, len: usize) {
+        self.buffer.remove(self.cursor..self.cursor + len);
+    }
+
+    /// Returns the character as it is displayed, taking the mask into account.
+    fn displayed(&self, character: char) -> char {
+        self.mask.unwrap_or(character)
+    }
+
+    /// Returns the column that the cursor is displayed at, before scrolling.
+    fn cursor_column(&self) -> usize {
+        self.text()[..self.cursor]
+            .chars()
+            .map(|character| width::of_char(self.displayed(character)))
+            .sum()
+    }
+
+    /// Returns how many columns the text needs to be scrolled by to keep the cursor visible in a
+    /// row that is `row_width` columns wide.
+    fn scroll_for(&self, row_width: usize) -> usize {
+        let cursor_column = self.cursor_column();
+        let cursor_width = self.text()[self.cursor..]
+            .chars()
+            .next()
+            .map_or(1, |character| width::of_char(self.displayed(character)).max(1));
+
+        if cursor_column < self.scroll {
+            cursor_column
+        } else if cursor_column + cursor_width > self.scroll + row_width {
+            (cursor_column + cursor_width).saturating_sub(row_width)
+        } else {
+            self.scroll
+        }
+    }
+
+    /// Moves the cursor to the character displayed at `column`, or to the end of the text if there
+    /// is no character there.
+    fn click(&mut self, column: usize) {
+        let mut current_column = 0;
+
+        for (index, character) in self.text().char_indices() {
+            current_column += width::of_char(self.displayed(character));
+
+            if current_column > column {
+                self.cursor = index;
+
+                return;
+            }
+        }
+
+        self.cursor = self.buffer.len();
+    }
+}
+
+impl Widget for TextInput<'_> {
+    fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> crate::Result<UpdateResult> {
+        self.submitted = false;
+
+        let previous = (self.buffer.len(), self.cursor);
+
+        match update_info {
+            UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown | KeyState::KeyHeld) if !character.is_control() => {
+                self.insert(character);
+            }
+            UpdateInfo::ModifiedKeyboardCharacter(character, modifiers, KeyState::KeyDown | KeyState::KeyHeld)
+                if !character.is_control() && modifiers.difference(Modifiers::SHIFT).is_empty() => {
+                self.insert(character);
+            }
+            // Holding Enter down doesn't submit the text again.
+            UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown) => self.submitted = true,
+            UpdateInfo::KeyboardInput(key, KeyState::KeyDown | KeyState::KeyHeld) => match key {
+                Key::Left => self.move_left(),
+                Key::Right => self.move_right(),
+                Key::Home => self.move_home(),
+                Key::End => self.move_end(),
+                Key::Backspace => self.backspace(),
+                Key::Delete => self.delete(),
+                _ => {}
+            },
+            UpdateInfo::CellClicked(x, 0, MouseButton::Primary) if x < terminal.width() => {
+                self.click(self.scroll + x);
+            }
+            _ => {}
+        }
+
+        self.scroll = self.scroll_for(terminal.width());
+
+        // Every edit either changes the length of the text or moves the cursor.
+        if self.submitted || (self.buffer.len(), self.cursor) != previous {
+            Ok(UpdateResult::Interacted)
+        } else {
+            Ok(UpdateResult::NoEvent)
+        }
+    }
+
+    fn draw(&self, mut terminal: impl Terminal) -> crate::Result<UpdateResult> {
+        let row_width = terminal.width();
+
+        if row_width == 0 {
+            return Ok(UpdateResult::NoEvent);
+        }
+
+        let scroll = self.scroll_for(row_width);
+        let text_style = self.style.inherits(self.cursor_style.reset());
+        let cursor_style = self.cursor_style.inherits(self.style);
+
+        let rect = Rectangle::of_size((row_width, 1));
+        let mut view = terminal.view_mut(rect).ok_or_else(|| Error::oob_with(rect.right_bottom()))?;
+        let mut writer = CellWriter::new(view.cells_mut(), row_width);
+
+        let mut column = 0;
+
+        for (index, character) in self.text().char_indices() {
+            let character = self.displayed(character);
+            let character_width = width::of_char(character);
+            let style = if index == self.cursor { cursor_style } else { text_style };
+
+            let start = column;
+            column += character_width;
+
+            if column <= scroll {
+                continue;
+            }
+
+            if start < scroll {
+                // A wide character that is cut in half by the left edge.
+                for _ in scroll..column {
+                    writer.write(' ', style);
+                }
+
+                continue;
+            }
+
+            if column > scroll + row_width || writer.write(character, style).is_none() {
+                break;
+            }
+        }
+
+        // Clear the rest of the row, so that deleted characters don't linger.
+        let mut style = if self.cursor == self.buffer.len() { cursor_style } else { text_style };
+
+        while writer.write(' ', style).is_some() {
+            style = text_style;
+        }
+
+        Ok(UpdateResult::NoEvent)
+    }
+
+    fn focusable(&self) -> usize {
+        1
+    }
+}
+
+impl BoundingBox for TextInput<'_> {
+    fn bounding_box(&self, rect: Rectangle) -> crate::Result<Rectangle> {
+        if rect.height() == 0 {
+            return Err(Error::RequestRescale {
+                new_width: rect.width(),
+                new_height: 1,
+            });
+        }
+
+        Ok(Rectangle::of_size((rect.width(), 1)))
+    }
+
+    fn completely_covers(&self, rectangle: Rectangle) -> bool {
+        rectangle.height() <= 1
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    use super::TextInput;
+    use crate::prelude::*;
+    use crate::terminal::{Cell, ConstantSize, Key, KeyState, UpdateInfo, UpdateResult};
+
+    fn typed<'a>(buffer: &'a mut [u8], text: &str) -> TextInput<'a> {
+        let terminal: ConstantSize<20, 1> = ConstantSize::new();
+        let mut input = TextInput::new(buffer);
+
+        for character in text.chars() {
+            input.update(UpdateInfo::KeyboardCharacter(character, KeyState::KeyDown), &terminal).expect("Should update successfully");
+        }
+
+        input
+    }
+
+    #[test]
+    fn zero_width_terminal() {
+        let mut buffer = [0; 16];
+        let input = typed(&mut buffer, "abc");
+        let mut terminal: ConstantSize<0, 1> = ConstantSize::new();
+
+        input.drawn(&mut terminal).expect("Should draw successfully");
+    }
+
+    #[test]
+    fn wide_characters() {
+        let mut buffer = [0; 16];
+        let input = typed(&mut buffer, "日本");
+
+        let mut narrow: ConstantSize<1, 1> = ConstantSize::new();
+        input.drawn(&mut narrow).expect("Should draw successfully");
+
+        let mut terminal: ConstantSize<5, 1> = ConstantSize::new();
+        input.drawn(&mut terminal).expect("Should draw successfully");
+
+        let row: [char; 5] = terminal.characters[0].map(|cell| cell.character);
+        assert_eq!(row, ['日', Cell::CONTINUATION, '本', Cell::CONTINUATION, ' ']);
+    }
+
+    #[test]
+    fn held_enter_submits_once() {
+        let terminal: ConstantSize<20, 1> = ConstantSize::new();
+        let mut buffer = [0; 16];
+        let mut input = typed(&mut buffer, "abc");
+
+        input.update(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyDown), &terminal).expect("Should update successfully");
+        assert!(input.submitted());
+
+        let result = input.update(UpdateInfo::KeyboardInput(Key::Enter, KeyState::KeyHeld), &terminal).expect("Should update successfully");
+        assert_eq!(result, UpdateResult::NoEvent);
+        assert!(!input.submitted());
+    }
+}
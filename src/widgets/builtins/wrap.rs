@@ -0,0 +1,359 @@
+//! The word-wrapping engine shared by the builtin text widgets.
+//!
+//! Text is fed in as a cloneable iterator of styled characters, so that the same engine works for
+//! a single `&str` and for a slice of styled spans without having to allocate.
+
+use crate::prelude::Terminal;
+use crate::style::Style;
+use crate::terminal::width::{self, CellWriter};
+use crate::terminal::{Rectangle, TerminalMut};
+use crate::widgets::{Alignment, Overflow};
+use crate::Error;
+
+/// Returns the width of a character, or `0` if it is wider than an entire row and will get dropped.
+fn width_of(character: char, row_width: usize) -> usize {
+    let width = width::of_char(character);
+
+    if width > row_width { 0 } else { width }
+}
+
+/// A single row of wrapped text.
+#[derive(Clone, Debug)]
+struct Line<I> {
+    /// The characters of the text, starting at the beginning of the line. Only the first `len`
+    /// belong to the line.
+    chars: I,
+    /// The number of characters in the line, not including any trailing spaces.
+    len: usize,
+    /// The display width of the line.
+    width: usize,
+    /// The number of spaces between the words of the line, not including any indentation.
+    spaces: usize,
+    /// Whether the line ends its paragraph, either because of a newline or because the text ended.
+    last: bool,
+}
+
+impl<I: Iterator<Item = (char, Style)> + Clone> Line<I> {
+    /// Returns the characters that belong to the line.
+    fn chars(&self) -> impl Iterator<Item = (char, Style)> {
+        self.chars.clone().take(self.len)
+    }
+}
+
+/// Splits text into [`Line`]s that are at most `row_width` columns wide.
+///
+/// Lines are broken at the last space that fits, or in the middle of a word if the word is wider
+/// than an entire row. Newlines always start a new line.
+#[derive(Clone, Debug)]
+struct Wrap<I> {
+    chars: Option<I>,
+    row_width: usize,
+    skip_spaces: bool,
+}
+
+impl<I> Wrap<I> {
+    /// Create a new [`Wrap`] over the characters of some text.
+    const fn new(chars: I, row_width: usize) -> Self {
+        Self {
+            chars: Some(chars),
+            row_width,
+            skip_spaces: false,
+        }
+    }
+}
+
+impl<I: Iterator<Item = (char, Style)> + Clone> Iterator for Wrap<I> {
+    type Item = Line<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row_width == 0 {
+            return None;
+        }
+
+        let mut start = self.chars.take()?;
+
+        if core::mem::take(&mut self.skip_spaces) {
+            let mut cursor = start.clone();
+
+            while let Some((' ', _)) = cursor.next() {
+                start = cursor.clone();
+            }
+        }
+
+        start.clone().next()?;
+
+        let mut cursor = start.clone();
+        let (mut len, mut width, mut spaces) = (0, 0, 0);
+        // The line without its trailing spaces.
+        let mut content = (0, 0, 0);
+        // The line as it was at the end of the last word, along with where the rest of the text starts.
+        let mut word_end = None;
+
+        let last = loop {
+            let before = cursor.clone();
+
+            let Some((character, _style)) = cursor.next() else {
+                break true;
+            };
+
+            if character == '\n' {
+                if cursor.clone().next().is_some() {
+                    self.chars = Some(cursor);
+                }
+
+                break true;
+            }
+
+            if character == ' ' {
+                if width + 1 > self.row_width {
+                    self.chars = Some(before);
+                    self.skip_spaces = true;
+
+                    break false;
+                }
+
+                if len > 0 && len == content.0 {
+                    word_end = Some((content, before));
+                }
+
+                len += 1;
+                width += 1;
+
+                // Indentation doesn't get stretched when the line is justified.
+                if content.0 > 0 {
+                    spaces += 1;
+                }
+
+                continue;
+            }
+
+            let character_width = width_of(character, self.row_width);
+
+            if width + character_width > self.row_width {
+                if let Some((word_content, rest)) = word_end {
+                    content = word_content;
+                    self.chars = Some(rest);
+                    self.skip_spaces = true;
+                } else {
+                    self.chars = Some(before);
+                }
+
+                break false;
+            }
+
+            len += 1;
+            width += character_width;
+            content = (len, width, spaces);
+        };
+
+        let (len, width, spaces) = content;
+
+        Some(Line { chars: start, len, width, spaces, last })
+    }
+}
+
+/// Returns the (width, height) that `chars` take up once wrapped into `rect`, cutting off any rows
+/// that don't fit.
+pub fn dimensions<I>(chars: I, overflow: Overflow, rect: Rectangle) -> (usize, usize)
+where
+    I: Iterator<Item = (char, Style)> + Clone,
+{
+    let (width, height, _truncated) = measure(chars, overflow, rect.width(), rect.height());
+
+    (width, height)
+}
+
+/// Returns whether wrapped text completely covers `rectangle`, for [`BoundingBox::completely_covers`].
+///
+/// Only an empty rectangle is guaranteed to be covered: lines are usually shorter than the widest
+/// line, and even justified text leaves its last line ragged, so some cells may not get drawn over.
+///
+/// [`BoundingBox::completely_covers`]: crate::widgets::BoundingBox::completely_covers
+pub const fn covers(rectangle: Rectangle) -> bool {
+    rectangle.area() == 0
+}
+
+/// Returns the width and height of the drawn text, and whether the last visible line gets an ellipsis.
+fn measure<I>(chars: I, overflow: Overflow, row_width: usize, rows: usize) -> (usize, usize, bool)
+where
+    I: Iterator<Item = (char, Style)> + Clone,
+{
+    let mut lines = Wrap::new(chars, row_width);
+    let mut width = 0;
+    let mut height = 0;
+
+    for line in lines.by_ref().take(rows) {
+        width = width.max(line.width);
+        height += 1;
+    }
+
+    let truncated = overflow == Overflow::Ellipsis && lines.next().is_some();
+
+    if truncated {
+        width = width.max(1);
+    }
+
+    (width, height, truncated)
+}
+
+/// Wraps `chars` and draws them into the terminal, aligning each line within the widest one.
+///
+/// # Errors
+///
+/// Only returns an error if the terminal reports dimensions that it can't create a view for.
+pub fn draw<I>(chars: I, alignment: Alignment, overflow: Overflow, mut terminal: impl Terminal) -> crate::Result<()>
+where
+    I: Iterator<Item = (char, Style)> + Clone,
+{
+    let row_width = terminal.width();
+    let rows = terminal.height();
+    let (block_width, height, truncated) = measure(chars.clone(), overflow, row_width, rows);
+
+    for (row, line) in Wrap::new(chars, row_width).take(height).enumerate() {
+        let ellipsis = truncated && row + 1 == height;
+
+        draw_line(&line, (row, row_width), block_width, alignment, ellipsis, &mut terminal)?;
+    }
+
+    Ok(())
+}
+
+/// Draws a single [`Line`] into the given row of the terminal.
+fn draw_line<I>(
+    line: &Line<I>,
+    (row, row_width): (usize, usize),
+    block_width: usize,
+    alignment: Alignment,
+    ellipsis: bool,
+    mut terminal: impl Terminal,
+) -> crate::Result<()>
+where
+    I: Iterator<Item = (char, Style)> + Clone,
+{
+    let limit = if ellipsis { block_width - 1 } else { block_width };
+    let (len, width) = truncate(line, limit, row_width);
+    let total = width + usize::from(ellipsis);
+
+    if total == 0 {
+        return Ok(());
+    }
+
+    let offset = match alignment {
+        Alignment::Left | Alignment::Justified => 0,
+        Alignment::Center => (block_width - total) / 2,
+        Alignment::Right => block_width - total,
+    };
+
+    let justify = alignment == Alignment::Justified && !line.last && !ellipsis && line.spaces > 0;
+    let extra = if justify { block_width - width } else { 0 };
+
+    let rect = Rectangle::new((offset, row), (offset + if justify { block_width } else { total }, row + 1));
+    let mut view = terminal.view_mut(rect).ok_or_else(|| Error::oob_with(rect.right_bottom()))?;
+    let mut writer = CellWriter::new(view.cells_mut(), rect.width());
+
+    let mut last_style = Style::new();
+    let mut spaces_seen = 0;
+    let mut indented = true;
+
+    for (character, style) in line.chars().take(len) {
+        writer.write(character, style).ok_or_else(|| Error::oob_with(rect.right_bottom()))?;
+        last_style = style;
+        indented &= character == ' ';
+
+        if justify && !indented && character == ' ' {
+            let padding = extra / line.spaces + usize::from(spaces_seen < extra % line.spaces);
+            spaces_seen += 1;
+
+            for _ in 0..padding {
+                writer.write(' ', style).ok_or_else(|| Error::oob_with(rect.right_bottom()))?;
+            }
+        }
+    }
+
+    if ellipsis {
+        writer.write('…', last_style).ok_or_else(|| Error::oob_with(rect.right_bottom()))?;
+    }
+
+    Ok(())
+}
+
+/// Returns the number of characters at the start of the line that fit within `limit` columns, and
+/// their width.
+fn truncate<I>(line: &Line<I>, limit: usize, row_width: usize) -> (usize, usize)
+where
+    I: Iterator<Item = (char, Style)> + Clone,
+{
+    if line.width <= limit {
+        return (line.len, line.width);
+    }
+
+    let mut len = 0;
+    let mut width = 0;
+
+    for (character, _style) in line.chars() {
+        let character_width = width_of(character, row_width);
+
+        if width + character_width > limit {
+            break;
+        }
+
+        len += 1;
+        width += character_width;
+    }
+
+    (len, width)
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use std::prelude::rust_2021::*;
+
+    use super::draw;
+    use crate::style::Style;
+    use crate::terminal::{Cell, ConstantSize};
+    use crate::widgets::{Alignment, Overflow};
+
+    fn rows<const WIDTH: usize, const HEIGHT: usize>(text: &str, overflow: Overflow) -> Vec<String> {
+        let mut terminal: ConstantSize<WIDTH, HEIGHT> = ConstantSize::new();
+        let chars = text.chars().map(|character| (character, Style::new()));
+
+        draw(chars, Alignment::Left, overflow, &mut terminal).expect("Should draw successfully");
+
+        terminal.characters.iter().map(|row| row.iter().map(|cell| cell.character).collect()).collect()
+    }
+
+    #[test]
+    fn zero_width_terminal() {
+        assert_eq!(rows::<0, 2>("abc", Overflow::Ellipsis), ["", ""]);
+    }
+
+    #[test]
+    fn exact_width_lines() {
+        assert_eq!(rows::<4, 3>("abcd efgh", Overflow::Clip), ["abcd", "efgh", "    "]);
+        assert_eq!(rows::<4, 3>("abcd\nef", Overflow::Clip), ["abcd", "ef  ", "    "]);
+    }
+
+    #[test]
+    fn wide_characters() {
+        let continuation = Cell::CONTINUATION;
+
+        assert_eq!(rows::<3, 2>("日本", Overflow::Clip), [format!("日{continuation} "), format!("本{continuation} ")]);
+        // Characters that are wider than an entire row are dropped.
+        assert_eq!(rows::<1, 2>("日a", Overflow::Clip), ["a", " "]);
+    }
+
+    #[test]
+    fn zero_width_characters() {
+        assert_eq!(rows::<2, 2>("a\u{200B}bc", Overflow::Clip), ["ab", "c "]);
+        assert_eq!(rows::<2, 1>("\u{200B}\u{200B}", Overflow::Clip), ["  "]);
+    }
+
+    #[test]
+    fn ellipsis_replaces_wide_character() {
+        // The ellipsis takes up the last column, so the wide character doesn't fit next to it.
+        assert_eq!(rows::<3, 1>("a日本", Overflow::Ellipsis), ["a… "]);
+    }
+}
@@ -32,6 +32,78 @@ impl Direction {
     }
 }
 
+/// Provides a horizontal alignment for [`Widget`]s that lay out lines of text.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub enum Alignment {
+    /// Lines start at the left edge.
+    #[default]
+    Left,
+    /// Lines are centered, leaning left when they can't be centered exactly.
+    Center,
+    /// Lines end at the right edge.
+    Right,
+    /// Lines are stretched to reach both edges by widening the spaces between words. The last line
+    /// of a paragraph is aligned to the left.
+    Justified,
+}
+
+/// Controls what a [`Widget`] does with content that doesn't fit inside of its [`Terminal`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub enum Overflow {
+    /// Content that doesn't fit is cut off.
+    #[default]
+    Clip,
+    /// Content that doesn't fit is cut off, and the end of what's left is replaced with an ellipsis (`…`).
+    Ellipsis,
+}
+
+/// The axis that a [`Widget`] is laid out along.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub enum Axis {
+    /// Left-to-right.
+    #[default]
+    Horizontal,
+    /// Top-to-bottom.
+    Vertical,
+}
+
+/// Separate distances for each side of a [`Widget`], like its margin or padding.
+///
+/// The constructors follow the CSS shorthands: [`Insets::all`] takes one value for every side,
+/// [`Insets::symmetric`] takes the vertical and then horizontal values, and [`Insets::new`] goes
+/// clockwise from the top.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Hash)]
+pub struct Insets<T> {
+    /// The distance from the top edge.
+    pub top: T,
+    /// The distance from the right edge.
+    pub right: T,
+    /// The distance from the bottom edge.
+    pub bottom: T,
+    /// The distance from the left edge.
+    pub left: T,
+}
+
+impl<T: Copy> Insets<T> {
+    /// Create new [`Insets`] with a different distance on each side, clockwise from the top.
+    #[must_use]
+    pub const fn new(top: T, right: T, bottom: T, left: T) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    /// Create new [`Insets`] with the same distance on every side.
+    #[must_use]
+    pub const fn all(distance: T) -> Self {
+        Self::new(distance, distance, distance, distance)
+    }
+
+    /// Create new [`Insets`] with one distance for the top and bottom, and another for the left and right.
+    #[must_use]
+    pub const fn symmetric(vertical: T, horizontal: T) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+}
+
 /// This trait defines the minimum requirements for a type to be capable of terminal display
 ///
 /// ## Example
@@ -132,9 +204,37 @@ pub trait Widget {
     fn drawn(&self, terminal: impl Terminal) -> crate::Result<UpdateResult> {
         self.draw(terminal)
     }
+
+    /// Returns the number of focusable widgets inside of this widget, including the widget itself.
+    ///
+    /// Widgets that react to keyboard input, like [`Checkbox`](builtins::checkbox::Checkbox), return
+    /// `1`. Widgets that contain other widgets, like [`Stacked`](builtins::Stacked), return the sum of
+    /// their children. Everything else returns `0`, which is the default.
+    fn focusable(&self) -> usize {
+        0
+    }
+
+    /// Moves the keyboard focus to the focusable widget at `focus`, counting focusable widgets in the
+    /// order that they are laid out (top-to-bottom and left-to-right). `None` removes the focus.
+    ///
+    /// Widgets that contain other widgets should only send keyboard input to the focused one. If
+    /// nothing is focused, keyboard input is sent to every widget.
+    ///
+    /// Does nothing by default.
+    fn set_focus(&mut self, focus: Option<usize>) {
+        let _ = focus;
+    }
+
+    /// Returns the index of the focused widget, as set by [`Widget::set_focus`].
+    ///
+    /// Returns `None` by default.
+    fn focused(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// The [`BoundingBox`] trait allows widgets to show the area of the [`Terminal`] that they cover.
+///
 /// This is useful for optimizing draw calls by only redrawing the area in the [`BoundingBox`],
 /// and it's also useful for composing widgets from other widgets because you can collect data
 /// about the widget's draw area.
@@ -165,92 +265,92 @@ pub trait BoundingBox: Widget {
     fn completely_covers(&self, rectangle: Rectangle) -> bool;
 
     /// The [`BoundingBox::covered_in`] method allows the widget to communicate whether it
-/// completely covers the space specified by its own bounding box in the specified
-/// [`TerminalConst`].
-///
-/// For example, if the widget is circular, it will return [`false`] because it doesn't
-/// completely cover the space in its bounding box.
-///
-/// # Parameters
-///
-/// - `terminal`: A reference to a [`TerminalConst`] instance that provides the necessary information about the terminal's size and dimensions.
-///
-/// # Return Value
-///
-/// This function returns a boolean value that indicates whether the widget completely covers the space specified by its own bounding box in the given [`TerminalConst`]. If the widget's bounding box cannot be determined or if it does not completely cover the space, the function returns [`false`]. Otherwise, it returns [`true`].
-
-/// # Examples
-///
-/// ```
-/// use tuit::Error;
-/// use tuit::widgets::{BoundingBox, Widget};
-/// use tuit::terminal::{ConstantSize, Rectangle, TerminalConst, UpdateInfo, UpdateResult};
-/// use tuit::prelude::*;
-///
-/// // Create a widget that completely covers the terminal.
-/// struct FullTerminalWidget;
-///
-/// impl Widget for FullTerminalWidget {
-///     fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> tuit::Result<UpdateResult> {
-///         Err(Error::Todo)
-///     }
-///     fn draw(&self, terminal: impl Terminal) -> tuit::Result<UpdateResult> {
-///         Err(Error::Todo)
-///     }
-/// }
-///
-/// impl BoundingBox for FullTerminalWidget {
-///     fn bounding_box(&self, _rect: Rectangle) -> tuit::Result<Rectangle> {
-///         Ok(Rectangle::new((0, 0), (20, 20)))
-///     }
-///
-///     fn completely_covers(&self, _rectangle: Rectangle) -> bool {
-///         // The widget completely covers the terminal, so it completely covers any rectangle.
-///         true
-///     }
-/// }
-///
-/// let full_terminal_widget = FullTerminalWidget;
-/// let terminal: ConstantSize<20, 20> = ConstantSize::new();
-///
-/// assert_eq!(full_terminal_widget.covered_in(&terminal), true);
-/// ```
-///
-/// ```
-/// use tuit::Error;
-/// use tuit::widgets::{BoundingBox, Widget};
-/// use tuit::terminal::{ConstantSize, Rectangle, TerminalConst, UpdateInfo, UpdateResult};
-/// use tuit::prelude::*;
-///
-/// // Create a widget that does not completely cover the terminal.
-/// struct PartialTerminalWidget;
-///
-/// impl Widget for PartialTerminalWidget {
-///     fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> tuit::Result<UpdateResult> {
-///         Err(Error::Todo)
-///     }
-///
-///     fn draw(&self, terminal: impl Terminal) -> tuit::Result<UpdateResult> {
-///         Err(Error::Todo)
-///     }
-/// }
-///
-/// impl BoundingBox for PartialTerminalWidget {
-///     fn bounding_box(&self, _rect: Rectangle) -> tuit::Result<Rectangle> {
-///         Ok(Rectangle::new((0, 0), (10, 10)))
-///     }
-///
-///     fn completely_covers(&self, _rectangle: Rectangle) -> bool {
-///         // The widget is not rectangular in shape, so it does not completely cover any rectangle.
-///         false
-///     }
-/// }
-///
-/// let partial_terminal_widget = PartialTerminalWidget;
-/// let terminal: ConstantSize<20, 20> = ConstantSize::new();
-///
-/// assert_eq!(partial_terminal_widget.covered_in(&terminal), false);
-/// ```
+    /// completely covers the space specified by its own bounding box in the specified
+    /// [`TerminalConst`].
+    ///
+    /// For example, if the widget is circular, it will return [`false`] because it doesn't
+    /// completely cover the space in its bounding box.
+    ///
+    /// # Parameters
+    ///
+    /// - `terminal`: A reference to a [`TerminalConst`] instance that provides the necessary information about the terminal's size and dimensions.
+    ///
+    /// # Return Value
+    ///
+    /// This function returns a boolean value that indicates whether the widget completely covers the space specified by its own bounding box in the given [`TerminalConst`]. If the widget's bounding box cannot be determined or if it does not completely cover the space, the function returns [`false`]. Otherwise, it returns [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tuit::Error;
+    /// use tuit::widgets::{BoundingBox, Widget};
+    /// use tuit::terminal::{ConstantSize, Rectangle, TerminalConst, UpdateInfo, UpdateResult};
+    /// use tuit::prelude::*;
+    ///
+    /// // Create a widget that completely covers the terminal.
+    /// struct FullTerminalWidget;
+    ///
+    /// impl Widget for FullTerminalWidget {
+    ///     fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> tuit::Result<UpdateResult> {
+    ///         Err(Error::Todo)
+    ///     }
+    ///     fn draw(&self, terminal: impl Terminal) -> tuit::Result<UpdateResult> {
+    ///         Err(Error::Todo)
+    ///     }
+    /// }
+    ///
+    /// impl BoundingBox for FullTerminalWidget {
+    ///     fn bounding_box(&self, _rect: Rectangle) -> tuit::Result<Rectangle> {
+    ///         Ok(Rectangle::new((0, 0), (20, 20)))
+    ///     }
+    ///
+    ///     fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+    ///         // The widget completely covers the terminal, so it completely covers any rectangle.
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let full_terminal_widget = FullTerminalWidget;
+    /// let terminal: ConstantSize<20, 20> = ConstantSize::new();
+    ///
+    /// assert_eq!(full_terminal_widget.covered_in(&terminal), true);
+    /// ```
+    ///
+    /// ```
+    /// use tuit::Error;
+    /// use tuit::widgets::{BoundingBox, Widget};
+    /// use tuit::terminal::{ConstantSize, Rectangle, TerminalConst, UpdateInfo, UpdateResult};
+    /// use tuit::prelude::*;
+    ///
+    /// // Create a widget that does not completely cover the terminal.
+    /// struct PartialTerminalWidget;
+    ///
+    /// impl Widget for PartialTerminalWidget {
+    ///     fn update(&mut self, update_info: UpdateInfo, terminal: impl TerminalConst) -> tuit::Result<UpdateResult> {
+    ///         Err(Error::Todo)
+    ///     }
+    ///
+    ///     fn draw(&self, terminal: impl Terminal) -> tuit::Result<UpdateResult> {
+    ///         Err(Error::Todo)
+    ///     }
+    /// }
+    ///
+    /// impl BoundingBox for PartialTerminalWidget {
+    ///     fn bounding_box(&self, _rect: Rectangle) -> tuit::Result<Rectangle> {
+    ///         Ok(Rectangle::new((0, 0), (10, 10)))
+    ///     }
+    ///
+    ///     fn completely_covers(&self, _rectangle: Rectangle) -> bool {
+    ///         // The widget is not rectangular in shape, so it does not completely cover any rectangle.
+    ///         false
+    ///     }
+    /// }
+    ///
+    /// let partial_terminal_widget = PartialTerminalWidget;
+    /// let terminal: ConstantSize<20, 20> = ConstantSize::new();
+    ///
+    /// assert_eq!(partial_terminal_widget.covered_in(&terminal), false);
+    /// ```
     fn covered_in(&self, terminal: impl TerminalConst) -> bool {
         let Ok(bounding_box) = self.bounding_box(terminal.bounding_box()) else {
             return false